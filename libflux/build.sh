@@ -29,5 +29,4 @@ docker run \
     --name $imagename \
     -v "$DIR:$SRC_DIR" \
     -v "$DIR/.cache:/home/builder/.cache" \
-    --env AR=llvm-ar \
     $imagename wasm-pack build --scope influxdata "$@"
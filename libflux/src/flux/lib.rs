@@ -19,9 +19,18 @@ use parser::Parser;
 
 pub const DEFAULT_PACKAGE_NAME: &str = "main";
 
+// Opaque types declared in include/influxdata/flux.h.
 #[allow(non_camel_case_types)]
 pub mod ctypes {
-    include!(concat!(env!("OUT_DIR"), "/ctypes.rs"));
+    #[repr(C)]
+    pub struct flux_ast_t {
+        _unused: [u8; 0],
+    }
+
+    #[repr(C)]
+    pub struct flux_error_t {
+        _unused: [u8; 0],
+    }
 }
 use ctypes::*;
 
@@ -1,5 +1,4 @@
 use std::collections::HashMap;
-use std::ffi::CString;
 use std::str;
 
 use crate::ast;
@@ -111,8 +110,7 @@ pub struct Parser {
 
 impl Parser {
     pub fn new(src: &str) -> Parser {
-        let cdata = CString::new(src).expect("CString::new failed");
-        let s = Scanner::new(cdata);
+        let s = Scanner::new(src);
         Parser {
             s,
             t: None,
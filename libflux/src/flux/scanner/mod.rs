@@ -1,23 +1,141 @@
-include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
-
-pub type CChar = u8;
-
 use std::collections::HashMap;
-use std::ffi::CString;
 use std::str;
 
+// TOK represents all possible Tokens.
+pub type TOK = u32;
+
+pub const TOK_ILLEGAL: TOK = 0;
+pub const TOK_EOF: TOK = 1;
+pub const TOK_COMMENT: TOK = 2;
+
+// Reserved keywords.
+pub const TOK_AND: TOK = 3;
+pub const TOK_OR: TOK = 4;
+pub const TOK_NOT: TOK = 5;
+pub const TOK_EMPTY: TOK = 6;
+pub const TOK_IN: TOK = 7;
+pub const TOK_IMPORT: TOK = 8;
+pub const TOK_PACKAGE: TOK = 9;
+pub const TOK_RETURN: TOK = 10;
+pub const TOK_OPTION: TOK = 11;
+pub const TOK_BUILTIN: TOK = 12;
+pub const TOK_TEST: TOK = 13;
+pub const TOK_IF: TOK = 14;
+pub const TOK_THEN: TOK = 15;
+pub const TOK_ELSE: TOK = 16;
+
+// Identifiers and literals.
+pub const TOK_IDENT: TOK = 17;
+pub const TOK_INT: TOK = 18;
+pub const TOK_FLOAT: TOK = 19;
+pub const TOK_STRING: TOK = 20;
+pub const TOK_REGEX: TOK = 21;
+pub const TOK_TIME: TOK = 22;
+pub const TOK_DURATION: TOK = 23;
+
+// Operators.
+pub const TOK_ADD: TOK = 24;
+pub const TOK_SUB: TOK = 25;
+pub const TOK_MUL: TOK = 26;
+pub const TOK_DIV: TOK = 27;
+pub const TOK_MOD: TOK = 28;
+pub const TOK_POW: TOK = 29;
+pub const TOK_EQ: TOK = 30;
+pub const TOK_LT: TOK = 31;
+pub const TOK_GT: TOK = 32;
+pub const TOK_LTE: TOK = 33;
+pub const TOK_GTE: TOK = 34;
+pub const TOK_NEQ: TOK = 35;
+pub const TOK_REGEXEQ: TOK = 36;
+pub const TOK_REGEXNEQ: TOK = 37;
+pub const TOK_ASSIGN: TOK = 38;
+pub const TOK_ARROW: TOK = 39;
+pub const TOK_LPAREN: TOK = 40;
+pub const TOK_RPAREN: TOK = 41;
+pub const TOK_LBRACK: TOK = 42;
+pub const TOK_RBRACK: TOK = 43;
+pub const TOK_LBRACE: TOK = 44;
+pub const TOK_RBRACE: TOK = 45;
+pub const TOK_COMMA: TOK = 46;
+pub const TOK_DOT: TOK = 47;
+pub const TOK_COLON: TOK = 48;
+pub const TOK_PIPE_FORWARD: TOK = 49;
+pub const TOK_PIPE_RECEIVE: TOK = 50;
+pub const TOK_EXISTS: TOK = 51;
+
+// String expression tokens.
+pub const TOK_QUOTE: TOK = 52;
+pub const TOK_STRINGEXPR: TOK = 53;
+pub const TOK_TEXT: TOK = 54;
+
+const KEYWORDS: [(&str, TOK); 15] = [
+    ("and", TOK_AND),
+    ("or", TOK_OR),
+    ("not", TOK_NOT),
+    ("empty", TOK_EMPTY),
+    ("in", TOK_IN),
+    ("import", TOK_IMPORT),
+    ("package", TOK_PACKAGE),
+    ("return", TOK_RETURN),
+    ("option", TOK_OPTION),
+    ("builtin", TOK_BUILTIN),
+    ("test", TOK_TEST),
+    ("if", TOK_IF),
+    ("then", TOK_THEN),
+    ("else", TOK_ELSE),
+    ("exists", TOK_EXISTS),
+];
+
+// Operators are matched longest first, so that "<=" wins over "<".
+const OPERATORS: [(&str, TOK); 28] = [
+    ("==", TOK_EQ),
+    ("<=", TOK_LTE),
+    (">=", TOK_GTE),
+    ("!=", TOK_NEQ),
+    ("=~", TOK_REGEXEQ),
+    ("!~", TOK_REGEXNEQ),
+    ("=>", TOK_ARROW),
+    ("<-", TOK_PIPE_RECEIVE),
+    ("|>", TOK_PIPE_FORWARD),
+    ("+", TOK_ADD),
+    ("-", TOK_SUB),
+    ("*", TOK_MUL),
+    ("/", TOK_DIV),
+    ("%", TOK_MOD),
+    ("^", TOK_POW),
+    ("<", TOK_LT),
+    (">", TOK_GT),
+    ("=", TOK_ASSIGN),
+    ("(", TOK_LPAREN),
+    (")", TOK_RPAREN),
+    ("[", TOK_LBRACK),
+    ("]", TOK_RBRACK),
+    ("{", TOK_LBRACE),
+    ("}", TOK_RBRACE),
+    (":", TOK_COLON),
+    (",", TOK_COMMA),
+    (".", TOK_DOT),
+    ("\"", TOK_QUOTE),
+];
+
+const DURATION_UNITS: [&str; 11] = ["mo", "ms", "us", "µs", "ns", "y", "w", "d", "h", "m", "s"];
+
+// Mode selects which set of rules is used to produce the next token.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Mode {
+    Main,
+    MainWithRegex,
+    StringExpr,
+}
+
 pub struct Scanner {
-    data: CString,
-    ps: *const CChar,
-    p: *const CChar,
-    pe: *const CChar,
-    eof: *const CChar,
-    last_newline: *const CChar,
+    data: String,
+    p: usize,
+    last_newline: usize,
     cur_line: u32,
-    checkpoint: *const CChar,
+    checkpoint: usize,
     checkpoint_line: u32,
-    checkpoint_last_newline: *const CChar,
-    token: TOK,
+    checkpoint_last_newline: usize,
     positions: HashMap<Position, u32>,
 }
 
@@ -41,39 +159,32 @@ pub struct Token {
 
 impl Scanner {
     // New creates a scanner with the provided input.
-    pub fn new(data: CString) -> Scanner {
-        let ptr = data.as_ptr();
-        let bytes = data.as_bytes();
-        let end = ((ptr as usize) + bytes.len()) as *const CChar;
+    pub fn new(data: &str) -> Scanner {
         Scanner {
-            data,
-            ps: ptr as *const CChar,
-            p: ptr as *const CChar,
-            pe: end,
-            eof: end,
-            last_newline: ptr as *const CChar,
+            data: data.to_string(),
+            p: 0,
+            last_newline: 0,
             cur_line: 1,
-            token: TOK_ILLEGAL,
-            checkpoint: ptr as *const CChar,
+            checkpoint: 0,
             checkpoint_line: 1,
-            checkpoint_last_newline: ptr as *const CChar,
+            checkpoint_last_newline: 0,
             positions: HashMap::new(),
         }
     }
 
     // scan produces the next token from the input.
     pub fn scan(&mut self) -> Token {
-        self._scan(0)
+        self._scan(Mode::Main)
     }
 
     // scan_with_regex produces the next token from the input accounting for regex.
     pub fn scan_with_regex(&mut self) -> Token {
-        self._scan(1)
+        self._scan(Mode::MainWithRegex)
     }
 
     // scan_string_expr produces the next token from the input in a string expression.
     pub fn scan_string_expr(&mut self) -> Token {
-        self._scan(2)
+        self._scan(Mode::StringExpr)
     }
 
     // unread will reset the Scanner to go back to the Scanner's location
@@ -90,9 +201,25 @@ impl Scanner {
         *self.positions.get(pos).expect("position should be in map")
     }
 
+    fn bytes(&self) -> &[u8] {
+        self.data.as_bytes()
+    }
+
+    fn byte_at(&self, i: usize) -> Option<u8> {
+        self.bytes().get(i).copied()
+    }
+
+    fn char_at(&self, i: usize) -> Option<char> {
+        self.data.get(i..).and_then(|s| s.chars().next())
+    }
+
+    fn column(&self, offset: usize) -> u32 {
+        (offset - self.last_newline + 1) as u32
+    }
+
     fn get_eof_token(&self) -> Token {
-        let data_len = self.data.as_bytes().len() as u32;
-        let column = self.eof as u32 - self.last_newline as u32 + 1;
+        let data_len = self.data.len() as u32;
+        let column = self.column(self.data.len());
         Token {
             tok: TOK_EOF,
             lit: String::from(""),
@@ -109,8 +236,8 @@ impl Scanner {
         }
     }
 
-    fn _scan(&mut self, mode: i32) -> Token {
-        if self.p == self.eof {
+    fn _scan(&mut self, mode: Mode) -> Token {
+        if self.p == self.data.len() {
             return self.get_eof_token();
         }
 
@@ -119,87 +246,39 @@ impl Scanner {
         self.checkpoint_line = self.cur_line;
         self.checkpoint_last_newline = self.last_newline;
 
-        let mut token_start = 0 as u32;
-        let mut token_start_line = 0 as u32;
-        let mut token_start_col = 0 as u32;
-        let mut token_end = 0 as u32;
-        let mut token_end_line = 0 as u32;
-        let mut token_end_col = 0 as u32;
-
-        let error = unsafe {
-            scan(
-                mode,
-                &mut self.p as *mut *const CChar,
-                self.ps as *const CChar,
-                self.pe as *const CChar,
-                self.eof as *const CChar,
-                &mut self.last_newline as *mut *const CChar,
-                &mut self.cur_line as *mut u32,
-                &mut self.token as *mut u32,
-                &mut token_start as *mut u32,
-                &mut token_start_line as *mut u32,
-                &mut token_start_col as *mut u32,
-                &mut token_end as *mut u32,
-                &mut token_end_line as *mut u32,
-                &mut token_end_col as *mut u32,
-            )
-        };
-        let t = if error != 0 {
-            // Execution failed meaning we hit a pattern that we don't support and
-            // doesn't produce a token. Use the unicode library to decode the next character
-            // in the sequence so we don't break up any unicode tokens.
-            let nc = unsafe {
-                std::str::from_utf8_unchecked(&self.data.as_bytes()[(token_start as usize)..])
-                    .chars()
-                    .next()
-            };
-            match nc {
-                Some(nc) => {
-                    let size = nc.len_utf8();
-                    // Advance the data pointer to after the character we just emitted.
-                    self.p = unsafe { self.p.add(size) };
-                    Token {
-                        tok: TOK_ILLEGAL,
-                        lit: nc.to_string(),
-                        start_offset: token_start,
-                        end_offset: token_start + size as u32,
-                        start_pos: Position {
-                            line: token_start_line,
-                            column: token_start_col,
-                        },
-                        end_pos: Position {
-                            line: token_start_line,
-                            column: token_start_col + size as u32,
-                        },
-                    }
-                }
-                // This should be impossible as we would have produced an EOF token
-                // instead, but going to handle this anyway as in this impossible scenario
-                // we would enter an infinite loop if we continued scanning past the token.
-                None => self.get_eof_token(),
-            }
-        } else if self.token == TOK_ILLEGAL && self.p == self.eof {
+        if mode != Mode::StringExpr {
+            self.skip_whitespace();
+        }
+
+        let t = if self.p == self.data.len() {
             // end of input
             self.get_eof_token()
         } else {
-            // No error or EOF, we can process the returned values normally.
-            let lit = unsafe {
-                str::from_utf8_unchecked(
-                    &self.data.as_bytes()[(token_start as usize)..(token_end as usize)],
-                )
+            let start = self.p;
+            let start_pos = Position {
+                line: self.cur_line,
+                column: self.column(start),
             };
+            let (tok, end) = match self.match_token(mode, start) {
+                Some(m) => m,
+                // We hit a pattern that we don't support and doesn't produce a token.
+                // Emit the next character in the sequence so we don't break up any
+                // unicode tokens.
+                None => {
+                    let size = self.char_at(start).map_or(1, char::len_utf8);
+                    (TOK_ILLEGAL, start + size)
+                }
+            };
+            self.advance_to(end);
             Token {
-                tok: self.token,
-                lit: String::from(lit),
-                start_offset: token_start,
-                end_offset: token_end,
-                start_pos: Position {
-                    line: token_start_line,
-                    column: token_start_col,
-                },
+                tok,
+                lit: String::from_utf8_lossy(&self.bytes()[start..end]).into_owned(),
+                start_offset: start as u32,
+                end_offset: end as u32,
+                start_pos,
                 end_pos: Position {
-                    line: token_end_line,
-                    column: token_end_col,
+                    line: self.cur_line,
+                    column: self.column(end),
                 },
             }
         };
@@ -218,6 +297,294 @@ impl Scanner {
             _ => t,
         }
     }
+
+    // advance_to moves the scanner to the given offset, keeping track
+    // of the newlines found along the way so that we can return the
+    // correct line/column for each token back to the caller.
+    fn advance_to(&mut self, end: usize) {
+        for i in self.p..end {
+            if self.bytes()[i] == b'\n' {
+                self.cur_line += 1;
+                self.last_newline = i + 1;
+            }
+        }
+        self.p = end;
+    }
+
+    // skip_whitespace discards the whitespace between tokens.
+    fn skip_whitespace(&mut self) {
+        let mut end = self.p;
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') | Some(b'\x0b')
+        | Some(b'\x0c') = self.byte_at(end)
+        {
+            end += 1;
+        }
+        self.advance_to(end);
+    }
+
+    // match_token returns the token starting at the given offset along with
+    // the offset where it ends. None is returned if no token matches.
+    fn match_token(&self, mode: Mode, start: usize) -> Option<(TOK, usize)> {
+        match mode {
+            Mode::Main => self.match_main(start),
+            Mode::MainWithRegex => match self.match_regex(start) {
+                Some(end) => Some((TOK_REGEX, end)),
+                None => self.match_main(start),
+            },
+            Mode::StringExpr => self.match_string_expr(start),
+        }
+    }
+
+    fn match_main(&self, start: usize) -> Option<(TOK, usize)> {
+        let c = self.char_at(start)?;
+        if self.bytes()[start..].starts_with(b"//") {
+            let end = match self.bytes()[start..].iter().position(|&b| b == b'\n') {
+                // The newline is part of the comment.
+                Some(n) => start + n + 1,
+                // A comment at the end of a file is considered valid.
+                None => self.data.len(),
+            };
+            return Some((TOK_COMMENT, end));
+        }
+        if c.is_alphabetic() || c == '_' {
+            let end = self.match_identifier(start);
+            let word = &self.data[start..end];
+            let tok = KEYWORDS
+                .iter()
+                .find(|(k, _)| *k == word)
+                .map_or(TOK_IDENT, |(_, t)| *t);
+            return Some((tok, end));
+        }
+        if c.is_ascii_digit() || c == '.' {
+            // The longest match wins, ties are resolved in favor of the
+            // first matching rule.
+            let mut best: Option<(TOK, usize)> = None;
+            for (tok, end) in &[
+                (TOK_INT, self.match_int(start)),
+                (TOK_FLOAT, self.match_float(start)),
+                (TOK_DURATION, self.match_duration(start)),
+                (TOK_TIME, self.match_date_time(start)),
+            ] {
+                if let Some(end) = *end {
+                    if best.map_or(true, |(_, e)| end > e) {
+                        best = Some((*tok, end));
+                    }
+                }
+            }
+            if best.is_some() {
+                return best;
+            }
+        }
+        if c == '"' {
+            if let Some(end) = self.match_string(start) {
+                return Some((TOK_STRING, end));
+            }
+        }
+        OPERATORS
+            .iter()
+            .find(|(op, _)| self.bytes()[start..].starts_with(op.as_bytes()))
+            .map(|(op, tok)| (*tok, start + op.len()))
+    }
+
+    fn match_identifier(&self, start: usize) -> usize {
+        let mut end = start;
+        while let Some(c) = self.char_at(end) {
+            if !(c.is_alphabetic() || c.is_ascii_digit() || c == '_') {
+                break;
+            }
+            end += c.len_utf8();
+        }
+        end
+    }
+
+    fn match_digits(&self, start: usize) -> usize {
+        let mut end = start;
+        while let Some(b'0'..=b'9') = self.byte_at(end) {
+            end += 1;
+        }
+        end
+    }
+
+    // int_lit = "0" | (digit - "0") digit*
+    fn match_int(&self, start: usize) -> Option<usize> {
+        match self.byte_at(start)? {
+            b'0' => Some(start + 1),
+            b'1'..=b'9' => Some(self.match_digits(start)),
+            _ => None,
+        }
+    }
+
+    // float_lit = (digit+ "." digit*) | ("." digit+)
+    fn match_float(&self, start: usize) -> Option<usize> {
+        let end = self.match_digits(start);
+        if self.byte_at(end) != Some(b'.') {
+            return None;
+        }
+        let frac = self.match_digits(end + 1);
+        if end == start && frac == end + 1 {
+            return None;
+        }
+        Some(frac)
+    }
+
+    // duration_lit = ( int_lit duration_unit )+
+    fn match_duration(&self, start: usize) -> Option<usize> {
+        let mut end = None;
+        let mut p = start;
+        while let Some(i) = self.match_int(p) {
+            let unit = DURATION_UNITS
+                .iter()
+                .find(|u| self.bytes()[i..].starts_with(u.as_bytes()));
+            match unit {
+                Some(u) => {
+                    p = i + u.len();
+                    end = Some(p);
+                }
+                None => break,
+            }
+        }
+        end
+    }
+
+    fn match_n_digits(&self, start: usize, n: usize) -> Option<usize> {
+        match self.bytes().get(start..start + n) {
+            Some(digits) if digits.iter().all(u8::is_ascii_digit) => Some(start + n),
+            _ => None,
+        }
+    }
+
+    fn match_byte(&self, start: usize, b: u8) -> Option<usize> {
+        if self.byte_at(start) == Some(b) {
+            Some(start + 1)
+        } else {
+            None
+        }
+    }
+
+    // date_time_lit = date ( "T" time )?
+    fn match_date_time(&self, start: usize) -> Option<usize> {
+        // date = digit{4} "-" digit{2} "-" digit{2}
+        let p = self.match_n_digits(start, 4)?;
+        let p = self.match_byte(p, b'-')?;
+        let p = self.match_n_digits(p, 2)?;
+        let p = self.match_byte(p, b'-')?;
+        let date = self.match_n_digits(p, 2)?;
+        Some(self.match_time(date).unwrap_or(date))
+    }
+
+    // "T" time, where
+    // time = digit{2} ":" digit{2} ":" digit{2} ( "." digit* )? time_offset?
+    fn match_time(&self, start: usize) -> Option<usize> {
+        let p = self.match_byte(start, b'T')?;
+        let p = self.match_n_digits(p, 2)?;
+        let p = self.match_byte(p, b':')?;
+        let p = self.match_n_digits(p, 2)?;
+        let p = self.match_byte(p, b':')?;
+        let mut p = self.match_n_digits(p, 2)?;
+        if let Some(frac) = self.match_byte(p, b'.') {
+            p = self.match_digits(frac);
+        }
+        Some(self.match_time_offset(p).unwrap_or(p))
+    }
+
+    // time_offset = "Z" | (("+" | "-") digit{2} ":" digit{2})
+    fn match_time_offset(&self, start: usize) -> Option<usize> {
+        match self.byte_at(start)? {
+            b'Z' => Some(start + 1),
+            b'+' | b'-' => {
+                let p = self.match_n_digits(start + 1, 2)?;
+                let p = self.match_byte(p, b':')?;
+                self.match_n_digits(p, 2)
+            }
+            _ => None,
+        }
+    }
+
+    // match_escape matches an escape sequence within a string literal,
+    // either escaped_char or byte_value.
+    fn match_escape(&self, start: usize) -> Option<usize> {
+        self.match_byte(start, b'\\')?;
+        match self.byte_at(start + 1)? {
+            b'n' | b'r' | b't' | b'\\' | b'"' => Some(start + 2),
+            b'$' => self.match_byte(start + 2, b'{'),
+            b'x' => {
+                let hex = self.bytes().get(start + 2..start + 4)?;
+                if hex.iter().all(u8::is_ascii_hexdigit) {
+                    Some(start + 4)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    // string_lit = '"' string_lit_char* "$"? :> '"'
+    fn match_string(&self, start: usize) -> Option<usize> {
+        let mut p = self.match_byte(start, b'"')?;
+        loop {
+            match self.byte_at(p)? {
+                b'"' => return Some(p + 1),
+                b'\\' => p = self.match_escape(p)?,
+                b'$' => match self.byte_at(p + 1)? {
+                    b'"' => return Some(p + 2),
+                    b'{' => return None,
+                    _ => p += 2,
+                },
+                _ => p += 1,
+            }
+        }
+    }
+
+    // regex_lit = "/" ( regex_unicode_value | byte_value )+ "/"
+    fn match_regex(&self, start: usize) -> Option<usize> {
+        self.match_byte(start, b'/')?;
+        let mut end = None;
+        for p in start + 1..self.data.len() {
+            if self.bytes()[p] != b'/' {
+                continue;
+            }
+            if p > start + 1 {
+                end = Some(p + 1);
+            }
+            // A slash may only continue the literal when it is escaped.
+            if p == start + 1 || self.bytes()[p - 1] != b'\\' {
+                break;
+            }
+        }
+        end
+    }
+
+    fn match_string_expr(&self, start: usize) -> Option<(TOK, usize)> {
+        if self.bytes()[start..].starts_with(b"${") {
+            return Some((TOK_STRINGEXPR, start + 2));
+        }
+        if self.byte_at(start) == Some(b'"') {
+            return Some((TOK_QUOTE, start + 1));
+        }
+        // (string_lit_char - "\"")+
+        let mut p = start;
+        loop {
+            let next = match self.byte_at(p) {
+                None | Some(b'"') => None,
+                Some(b'\\') => self.match_escape(p),
+                Some(b'$') => match self.byte_at(p + 1) {
+                    None | Some(b'{') => None,
+                    Some(_) => Some(p + 2),
+                },
+                Some(_) => Some(p + 1),
+            };
+            match next {
+                Some(n) => p = n,
+                None => break,
+            }
+        }
+        if p > start {
+            Some((TOK_TEXT, p))
+        } else {
+            None
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,11 +1,9 @@
 use super::*;
-use std::ffi::CString;
 
 #[test]
 fn test_scan() {
     let text = "from(bucket:\"foo\") |> range(start: -1m)";
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan(),
         Token {
@@ -251,8 +249,7 @@ fn test_scan() {
 #[test]
 fn test_scan_with_regex() {
     let text = "a + b =~ /.*[0-9]/ / 2";
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan_with_regex(),
         Token {
@@ -370,8 +367,7 @@ fn test_scan_with_regex() {
 #[test]
 fn test_scan_string_expr_simple() {
     let text = r#""${a + b}""#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan_string_expr(),
         Token {
@@ -430,8 +426,7 @@ fn test_scan_string_expr_simple() {
 #[test]
 fn test_scan_string_expr_start_with_text() {
     let text = r#""a + b = ${a + b}""#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan_string_expr(),
         Token {
@@ -513,8 +508,7 @@ fn test_scan_string_expr_start_with_text() {
 #[test]
 fn test_scan_string_expr_multiple() {
     let text = r#""a + b = ${a + b} and a - b = ${a - b}""#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan_string_expr(),
         Token {
@@ -630,8 +624,7 @@ fn test_scan_string_expr_multiple() {
 #[test]
 fn test_scan_string_expr_end_with_text() {
     let text = r#""a + b = ${a + b} and a - b = ?""#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan_string_expr(),
         Token {
@@ -713,8 +706,7 @@ fn test_scan_string_expr_end_with_text() {
 #[test]
 fn test_scan_string_expr_escaped_quotes() {
     let text = r#""these \"\" are escaped quotes""#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan_string_expr(),
         Token {
@@ -762,8 +754,7 @@ fn test_scan_string_expr_escaped_quotes() {
 #[test]
 fn test_scan_string_expr_not_escaped_quotes() {
     let text = r#""this " is not an escaped quote""#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan_string_expr(),
         Token {
@@ -833,8 +824,7 @@ fn test_scan_string_expr_not_escaped_quotes() {
 #[test]
 fn test_scan_unread() {
     let text = "1 / 2 / 3";
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan_with_regex(),
         Token {
@@ -963,8 +953,7 @@ fn test_scan_unread_with_newlines() {
 
 
 /foo/"#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan(),
         Token {
@@ -1020,8 +1009,7 @@ fn test_scan_with_regex_unread() {
     let text = r#"3 * / 1
          y
     "#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
 
     let mut toks = vec![];
     toks.push(s.scan()); // 3
@@ -1106,8 +1094,7 @@ fn test_unclosed_quote() {
     let text = r#"x = "foo
         bar
         baz"#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     let mut toks = vec![];
     toks.push(s.scan()); // x
     toks.push(s.scan()); // =
@@ -1200,8 +1187,7 @@ a
 // last but not least.
 1
 // ok, that's it."#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan(),
         Token {
@@ -1243,8 +1229,7 @@ a
     );
 
     // with regex
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan_with_regex(),
         Token {
@@ -1289,8 +1274,7 @@ a
 #[test]
 fn test_scan_eof() {
     let text = r#""#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     // idempotence with and without regex.
     assert_eq!(
         s.scan(),
@@ -1370,8 +1354,7 @@ fn test_scan_eof_trailing_spaces() {
     text.push(' ');
     text.push('\t');
     text.push('\t');
-    let cdata = CString::new(text.clone()).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(&text);
     assert_eq!(
         s.scan(),
         Token {
@@ -1384,8 +1367,7 @@ fn test_scan_eof_trailing_spaces() {
         }
     );
 
-    let cdata = CString::new(text.clone()).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(&text);
     assert_eq!(
         s.scan_with_regex(),
         Token {
@@ -1402,8 +1384,7 @@ fn test_scan_eof_trailing_spaces() {
 #[test]
 fn test_illegal() {
     let text = r#"legal @ illegal"#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata.clone());
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan(),
         Token {
@@ -1442,7 +1423,7 @@ fn test_illegal() {
     );
 
     // unread
-    let mut s = Scanner::new(cdata.clone());
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan(),
         Token {
@@ -1493,7 +1474,7 @@ fn test_illegal() {
     );
 
     // with regex
-    let mut s = Scanner::new(cdata.clone());
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan_with_regex(),
         Token {
@@ -1532,7 +1513,7 @@ fn test_illegal() {
     );
 
     // unread
-    let mut s = Scanner::new(cdata.clone());
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan_with_regex(),
         Token {
@@ -1586,8 +1567,7 @@ fn test_illegal() {
 #[test]
 fn test_scan_duration() {
     let text = r#"dur = 1y3mo2w1d4h1m30s1ms2µs70ns"#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan(),
         Token {
@@ -1657,8 +1637,7 @@ c = 1 + 2
 
 
 "#;
-    let cdata = CString::new(text).expect("CString::new failed");
-    let mut s = Scanner::new(cdata);
+    let mut s = Scanner::new(text);
     assert_eq!(
         s.scan(),
         Token {
@@ -1810,3 +1789,42 @@ c = 1 + 2
         })
     );
 }
+
+#[test]
+fn test_scan_nul() {
+    let text = "a\u{0}b";
+    let mut s = Scanner::new(text);
+    assert_eq!(
+        s.scan(),
+        Token {
+            tok: TOK_IDENT,
+            lit: String::from("a"),
+            start_offset: 0,
+            end_offset: 1,
+            start_pos: Position { line: 1, column: 1 },
+            end_pos: Position { line: 1, column: 2 }
+        }
+    );
+    assert_eq!(
+        s.scan(),
+        Token {
+            tok: TOK_ILLEGAL,
+            lit: String::from("\u{0}"),
+            start_offset: 1,
+            end_offset: 2,
+            start_pos: Position { line: 1, column: 2 },
+            end_pos: Position { line: 1, column: 3 }
+        }
+    );
+    assert_eq!(
+        s.scan(),
+        Token {
+            tok: TOK_IDENT,
+            lit: String::from("b"),
+            start_offset: 2,
+            end_offset: 3,
+            start_pos: Position { line: 1, column: 3 },
+            end_pos: Position { line: 1, column: 4 }
+        }
+    );
+}
@@ -1,1999 +0,0 @@
-# The following Ragel file was autogenerated with unicode2ragel.rb 
-# from: http://www.unicode.org/Public/5.1.0/ucd/DerivedCoreProperties.txt
-#
-# It defines ualpha, udigit, ualnum.
-#
-# To use this, make sure that your alphtype is set to unsigned char,
-# and that your input is in utf8.
-
-%%{
-    machine WChar;
-    ualpha = 
-        0x41..0x5A              #L&  [26] LATIN CAPITAL LETTER A..LATIN CAPI...
-      | 0x61..0x7A              #L&  [26] LATIN SMALL LETTER A..LATIN SMALL ...
-      | 0xC2 0xAA               #L&       FEMININE ORDINAL INDICATOR
-      | 0xC2 0xB5               #L&       MICRO SIGN
-      | 0xC2 0xBA               #L&       MASCULINE ORDINAL INDICATOR
-      | 0xC3 0x80..0x96         #L&  [23] LATIN CAPITAL LETTER A WITH GRAVE....
-      | 0xC3 0x98..0xB6         #L&  [31] LATIN CAPITAL LETTER O WITH STROKE...
-      | 0xC3 0xB8..0xFF         #L& [195] LATIN SMALL LETTER O WITH STROKE.....
-      | 0xC4..0xC5 0x00..0xFF   #
-      | 0xC6 0x00..0xBA         #
-      | 0xC6 0xBB               #Lo       LATIN LETTER TWO WITH STROKE
-      | 0xC6 0xBC..0xBF         #L&   [4] LATIN CAPITAL LETTER TONE FIVE..LA...
-      | 0xC7 0x80..0x83         #Lo   [4] LATIN LETTER DENTAL CLICK..LATIN L...
-      | 0xC7 0x84..0xFF         #L& [208] LATIN CAPITAL LETTER DZ WITH CARON...
-      | 0xC8..0xC9 0x00..0xFF   #
-      | 0xCA 0x00..0x93         #
-      | 0xCA 0x94               #Lo       LATIN LETTER GLOTTAL STOP
-      | 0xCA 0x95..0xAF         #L&  [27] LATIN LETTER PHARYNGEAL VOICED FRI...
-      | 0xCA 0xB0..0xFF         #Lm  [18] MODIFIER LETTER SMALL H..MODIFIER ...
-      | 0xCB 0x00..0x81         #
-      | 0xCB 0x86..0x91         #Lm  [12] MODIFIER LETTER CIRCUMFLEX ACCENT....
-      | 0xCB 0xA0..0xA4         #Lm   [5] MODIFIER LETTER SMALL GAMMA..MODIF...
-      | 0xCB 0xAC               #Lm       MODIFIER LETTER VOICING
-      | 0xCB 0xAE               #Lm       MODIFIER LETTER DOUBLE APOSTROPHE
-      | 0xCD 0x85               #Mn       COMBINING GREEK YPOGEGRAMMENI
-      | 0xCD 0xB0..0xB3         #L&   [4] GREEK CAPITAL LETTER HETA..GREEK S...
-      | 0xCD 0xB4               #Lm       GREEK NUMERAL SIGN
-      | 0xCD 0xB6..0xB7         #L&   [2] GREEK CAPITAL LETTER PAMPHYLIAN DI...
-      | 0xCD 0xBA               #Lm       GREEK YPOGEGRAMMENI
-      | 0xCD 0xBB..0xBD         #L&   [3] GREEK SMALL REVERSED LUNATE SIGMA ...
-      | 0xCE 0x86               #L&       GREEK CAPITAL LETTER ALPHA WITH TONOS
-      | 0xCE 0x88..0x8A         #L&   [3] GREEK CAPITAL LETTER EPSILON WITH ...
-      | 0xCE 0x8C               #L&       GREEK CAPITAL LETTER OMICRON WITH ...
-      | 0xCE 0x8E..0xA1         #L&  [20] GREEK CAPITAL LETTER UPSILON WITH ...
-      | 0xCE 0xA3..0xFF         #L&  [83] GREEK CAPITAL LETTER SIGMA..GREEK ...
-      | 0xCF 0x00..0xB5         #
-      | 0xCF 0xB7..0xFF         #L& [139] GREEK CAPITAL LETTER SHO..CYRILLIC...
-      | 0xD0..0xD1 0x00..0xFF   #
-      | 0xD2 0x00..0x81         #
-      | 0xD2 0x8A..0xFF         #L& [154] CYRILLIC CAPITAL LETTER SHORT I WI...
-      | 0xD3..0xD3 0x00..0xFF   #
-      | 0xD4 0x00..0xA3         #
-      | 0xD4 0xB1..0xFF         #L&  [38] ARMENIAN CAPITAL LETTER AYB..ARMEN...
-      | 0xD5 0x00..0x96         #
-      | 0xD5 0x99               #Lm       ARMENIAN MODIFIER LETTER LEFT HALF...
-      | 0xD5 0xA1..0xFF         #L&  [39] ARMENIAN SMALL LETTER AYB..ARMENIA...
-      | 0xD6 0x00..0x87         #
-      | 0xD6 0xB0..0xBD         #Mn  [14] HEBREW POINT SHEVA..HEBREW POINT M...
-      | 0xD6 0xBF               #Mn       HEBREW POINT RAFE
-      | 0xD7 0x81..0x82         #Mn   [2] HEBREW POINT SHIN DOT..HEBREW POIN...
-      | 0xD7 0x84..0x85         #Mn   [2] HEBREW MARK UPPER DOT..HEBREW MARK...
-      | 0xD7 0x87               #Mn       HEBREW POINT QAMATS QATAN
-      | 0xD7 0x90..0xAA         #Lo  [27] HEBREW LETTER ALEF..HEBREW LETTER TAV
-      | 0xD7 0xB0..0xB2         #Lo   [3] HEBREW LIGATURE YIDDISH DOUBLE VAV...
-      | 0xD8 0x90..0x9A         #Mn  [11] ARABIC SIGN SALLALLAHOU ALAYHE WAS...
-      | 0xD8 0xA1..0xBF         #Lo  [31] ARABIC LETTER HAMZA..ARABIC LETTER...
-      | 0xD9 0x80               #Lm       ARABIC TATWEEL
-      | 0xD9 0x81..0x8A         #Lo  [10] ARABIC LETTER FEH..ARABIC LETTER YEH
-      | 0xD9 0x8B..0x97         #Mn  [13] ARABIC FATHATAN..ARABIC INVERTED D...
-      | 0xD9 0x99..0x9E         #Mn   [6] ARABIC ZWARAKAY..ARABIC FATHA WITH...
-      | 0xD9 0xAE..0xAF         #Lo   [2] ARABIC LETTER DOTLESS BEH..ARABIC ...
-      | 0xD9 0xB0               #Mn       ARABIC LETTER SUPERSCRIPT ALEF
-      | 0xD9 0xB1..0xFF         #Lo  [99] ARABIC LETTER ALEF WASLA..ARABIC L...
-      | 0xDA..0xDA 0x00..0xFF   #
-      | 0xDB 0x00..0x93         #
-      | 0xDB 0x95               #Lo       ARABIC LETTER AE
-      | 0xDB 0x96..0x9C         #Mn   [7] ARABIC SMALL HIGH LIGATURE SAD WIT...
-      | 0xDB 0xA1..0xA4         #Mn   [4] ARABIC SMALL HIGH DOTLESS HEAD OF ...
-      | 0xDB 0xA5..0xA6         #Lm   [2] ARABIC SMALL WAW..ARABIC SMALL YEH
-      | 0xDB 0xA7..0xA8         #Mn   [2] ARABIC SMALL HIGH YEH..ARABIC SMAL...
-      | 0xDB 0xAD               #Mn       ARABIC SMALL LOW MEEM
-      | 0xDB 0xAE..0xAF         #Lo   [2] ARABIC LETTER DAL WITH INVERTED V....
-      | 0xDB 0xBA..0xBC         #Lo   [3] ARABIC LETTER SHEEN WITH DOT BELOW...
-      | 0xDB 0xBF               #Lo       ARABIC LETTER HEH WITH INVERTED V
-      | 0xDC 0x90               #Lo       SYRIAC LETTER ALAPH
-      | 0xDC 0x91               #Mn       SYRIAC LETTER SUPERSCRIPT ALAPH
-      | 0xDC 0x92..0xAF         #Lo  [30] SYRIAC LETTER BETH..SYRIAC LETTER ...
-      | 0xDC 0xB0..0xBF         #Mn  [16] SYRIAC PTHAHA ABOVE..SYRIAC RWAHA
-      | 0xDD 0x8D..0xFF         #Lo  [89] SYRIAC LETTER SOGDIAN ZHAIN..THAAN...
-      | 0xDE 0x00..0xA5         #
-      | 0xDE 0xA6..0xB0         #Mn  [11] THAANA ABAFILI..THAANA SUKUN
-      | 0xDE 0xB1               #Lo       THAANA LETTER NAA
-      | 0xDF 0x8A..0xAA         #Lo  [33] NKO LETTER A..NKO LETTER JONA RA
-      | 0xDF 0xB4..0xB5         #Lm   [2] NKO HIGH TONE APOSTROPHE..NKO LOW ...
-      | 0xDF 0xBA               #Lm       NKO LAJANYALAN
-      | 0xE0 0xA4 0x81..0x82    #Mn   [2] DEVANAGARI SIGN CANDRABINDU..DEVAN...
-      | 0xE0 0xA4 0x83          #Mc       DEVANAGARI SIGN VISARGA
-      | 0xE0 0xA4 0x84..0xB9    #Lo  [54] DEVANAGARI LETTER SHORT A..DEVANAG...
-      | 0xE0 0xA4 0xBD          #Lo       DEVANAGARI SIGN AVAGRAHA
-      | 0xE0 0xA4 0xBE..0xFF    #Mc   [3] DEVANAGARI VOWEL SIGN AA..DEVANAGA...
-      | 0xE0 0xA5 0x00..0x80    #
-      | 0xE0 0xA5 0x81..0x88    #Mn   [8] DEVANAGARI VOWEL SIGN U..DEVANAGAR...
-      | 0xE0 0xA5 0x89..0x8C    #Mc   [4] DEVANAGARI VOWEL SIGN CANDRA O..DE...
-      | 0xE0 0xA5 0x90          #Lo       DEVANAGARI OM
-      | 0xE0 0xA5 0x98..0xA1    #Lo  [10] DEVANAGARI LETTER QA..DEVANAGARI L...
-      | 0xE0 0xA5 0xA2..0xA3    #Mn   [2] DEVANAGARI VOWEL SIGN VOCALIC L..D...
-      | 0xE0 0xA5 0xB1          #Lm       DEVANAGARI SIGN HIGH SPACING DOT
-      | 0xE0 0xA5 0xB2          #Lo       DEVANAGARI LETTER CANDRA A
-      | 0xE0 0xA5 0xBB..0xBF    #Lo   [5] DEVANAGARI LETTER GGA..DEVANAGARI ...
-      | 0xE0 0xA6 0x81          #Mn       BENGALI SIGN CANDRABINDU
-      | 0xE0 0xA6 0x82..0x83    #Mc   [2] BENGALI SIGN ANUSVARA..BENGALI SIG...
-      | 0xE0 0xA6 0x85..0x8C    #Lo   [8] BENGALI LETTER A..BENGALI LETTER V...
-      | 0xE0 0xA6 0x8F..0x90    #Lo   [2] BENGALI LETTER E..BENGALI LETTER AI
-      | 0xE0 0xA6 0x93..0xA8    #Lo  [22] BENGALI LETTER O..BENGALI LETTER NA
-      | 0xE0 0xA6 0xAA..0xB0    #Lo   [7] BENGALI LETTER PA..BENGALI LETTER RA
-      | 0xE0 0xA6 0xB2          #Lo       BENGALI LETTER LA
-      | 0xE0 0xA6 0xB6..0xB9    #Lo   [4] BENGALI LETTER SHA..BENGALI LETTER HA
-      | 0xE0 0xA6 0xBD          #Lo       BENGALI SIGN AVAGRAHA
-      | 0xE0 0xA6 0xBE..0xFF    #Mc   [3] BENGALI VOWEL SIGN AA..BENGALI VOW...
-      | 0xE0 0xA7 0x00..0x80    #
-      | 0xE0 0xA7 0x81..0x84    #Mn   [4] BENGALI VOWEL SIGN U..BENGALI VOWE...
-      | 0xE0 0xA7 0x87..0x88    #Mc   [2] BENGALI VOWEL SIGN E..BENGALI VOWE...
-      | 0xE0 0xA7 0x8B..0x8C    #Mc   [2] BENGALI VOWEL SIGN O..BENGALI VOWE...
-      | 0xE0 0xA7 0x8E          #Lo       BENGALI LETTER KHANDA TA
-      | 0xE0 0xA7 0x97          #Mc       BENGALI AU LENGTH MARK
-      | 0xE0 0xA7 0x9C..0x9D    #Lo   [2] BENGALI LETTER RRA..BENGALI LETTER...
-      | 0xE0 0xA7 0x9F..0xA1    #Lo   [3] BENGALI LETTER YYA..BENGALI LETTER...
-      | 0xE0 0xA7 0xA2..0xA3    #Mn   [2] BENGALI VOWEL SIGN VOCALIC L..BENG...
-      | 0xE0 0xA7 0xB0..0xB1    #Lo   [2] BENGALI LETTER RA WITH MIDDLE DIAG...
-      | 0xE0 0xA8 0x81..0x82    #Mn   [2] GURMUKHI SIGN ADAK BINDI..GURMUKHI...
-      | 0xE0 0xA8 0x83          #Mc       GURMUKHI SIGN VISARGA
-      | 0xE0 0xA8 0x85..0x8A    #Lo   [6] GURMUKHI LETTER A..GURMUKHI LETTER UU
-      | 0xE0 0xA8 0x8F..0x90    #Lo   [2] GURMUKHI LETTER EE..GURMUKHI LETTE...
-      | 0xE0 0xA8 0x93..0xA8    #Lo  [22] GURMUKHI LETTER OO..GURMUKHI LETTE...
-      | 0xE0 0xA8 0xAA..0xB0    #Lo   [7] GURMUKHI LETTER PA..GURMUKHI LETTE...
-      | 0xE0 0xA8 0xB2..0xB3    #Lo   [2] GURMUKHI LETTER LA..GURMUKHI LETTE...
-      | 0xE0 0xA8 0xB5..0xB6    #Lo   [2] GURMUKHI LETTER VA..GURMUKHI LETTE...
-      | 0xE0 0xA8 0xB8..0xB9    #Lo   [2] GURMUKHI LETTER SA..GURMUKHI LETTE...
-      | 0xE0 0xA8 0xBE..0xFF    #Mc   [3] GURMUKHI VOWEL SIGN AA..GURMUKHI V...
-      | 0xE0 0xA9 0x00..0x80    #
-      | 0xE0 0xA9 0x81..0x82    #Mn   [2] GURMUKHI VOWEL SIGN U..GURMUKHI VO...
-      | 0xE0 0xA9 0x87..0x88    #Mn   [2] GURMUKHI VOWEL SIGN EE..GURMUKHI V...
-      | 0xE0 0xA9 0x8B..0x8C    #Mn   [2] GURMUKHI VOWEL SIGN OO..GURMUKHI V...
-      | 0xE0 0xA9 0x91          #Mn       GURMUKHI SIGN UDAAT
-      | 0xE0 0xA9 0x99..0x9C    #Lo   [4] GURMUKHI LETTER KHHA..GURMUKHI LET...
-      | 0xE0 0xA9 0x9E          #Lo       GURMUKHI LETTER FA
-      | 0xE0 0xA9 0xB0..0xB1    #Mn   [2] GURMUKHI TIPPI..GURMUKHI ADDAK
-      | 0xE0 0xA9 0xB2..0xB4    #Lo   [3] GURMUKHI IRI..GURMUKHI EK ONKAR
-      | 0xE0 0xA9 0xB5          #Mn       GURMUKHI SIGN YAKASH
-      | 0xE0 0xAA 0x81..0x82    #Mn   [2] GUJARATI SIGN CANDRABINDU..GUJARAT...
-      | 0xE0 0xAA 0x83          #Mc       GUJARATI SIGN VISARGA
-      | 0xE0 0xAA 0x85..0x8D    #Lo   [9] GUJARATI LETTER A..GUJARATI VOWEL ...
-      | 0xE0 0xAA 0x8F..0x91    #Lo   [3] GUJARATI LETTER E..GUJARATI VOWEL ...
-      | 0xE0 0xAA 0x93..0xA8    #Lo  [22] GUJARATI LETTER O..GUJARATI LETTER NA
-      | 0xE0 0xAA 0xAA..0xB0    #Lo   [7] GUJARATI LETTER PA..GUJARATI LETTE...
-      | 0xE0 0xAA 0xB2..0xB3    #Lo   [2] GUJARATI LETTER LA..GUJARATI LETTE...
-      | 0xE0 0xAA 0xB5..0xB9    #Lo   [5] GUJARATI LETTER VA..GUJARATI LETTE...
-      | 0xE0 0xAA 0xBD          #Lo       GUJARATI SIGN AVAGRAHA
-      | 0xE0 0xAA 0xBE..0xFF    #Mc   [3] GUJARATI VOWEL SIGN AA..GUJARATI V...
-      | 0xE0 0xAB 0x00..0x80    #
-      | 0xE0 0xAB 0x81..0x85    #Mn   [5] GUJARATI VOWEL SIGN U..GUJARATI VO...
-      | 0xE0 0xAB 0x87..0x88    #Mn   [2] GUJARATI VOWEL SIGN E..GUJARATI VO...
-      | 0xE0 0xAB 0x89          #Mc       GUJARATI VOWEL SIGN CANDRA O
-      | 0xE0 0xAB 0x8B..0x8C    #Mc   [2] GUJARATI VOWEL SIGN O..GUJARATI VO...
-      | 0xE0 0xAB 0x90          #Lo       GUJARATI OM
-      | 0xE0 0xAB 0xA0..0xA1    #Lo   [2] GUJARATI LETTER VOCALIC RR..GUJARA...
-      | 0xE0 0xAB 0xA2..0xA3    #Mn   [2] GUJARATI VOWEL SIGN VOCALIC L..GUJ...
-      | 0xE0 0xAC 0x81          #Mn       ORIYA SIGN CANDRABINDU
-      | 0xE0 0xAC 0x82..0x83    #Mc   [2] ORIYA SIGN ANUSVARA..ORIYA SIGN VI...
-      | 0xE0 0xAC 0x85..0x8C    #Lo   [8] ORIYA LETTER A..ORIYA LETTER VOCAL...
-      | 0xE0 0xAC 0x8F..0x90    #Lo   [2] ORIYA LETTER E..ORIYA LETTER AI
-      | 0xE0 0xAC 0x93..0xA8    #Lo  [22] ORIYA LETTER O..ORIYA LETTER NA
-      | 0xE0 0xAC 0xAA..0xB0    #Lo   [7] ORIYA LETTER PA..ORIYA LETTER RA
-      | 0xE0 0xAC 0xB2..0xB3    #Lo   [2] ORIYA LETTER LA..ORIYA LETTER LLA
-      | 0xE0 0xAC 0xB5..0xB9    #Lo   [5] ORIYA LETTER VA..ORIYA LETTER HA
-      | 0xE0 0xAC 0xBD          #Lo       ORIYA SIGN AVAGRAHA
-      | 0xE0 0xAC 0xBE          #Mc       ORIYA VOWEL SIGN AA
-      | 0xE0 0xAC 0xBF          #Mn       ORIYA VOWEL SIGN I
-      | 0xE0 0xAD 0x80          #Mc       ORIYA VOWEL SIGN II
-      | 0xE0 0xAD 0x81..0x84    #Mn   [4] ORIYA VOWEL SIGN U..ORIYA VOWEL SI...
-      | 0xE0 0xAD 0x87..0x88    #Mc   [2] ORIYA VOWEL SIGN E..ORIYA VOWEL SI...
-      | 0xE0 0xAD 0x8B..0x8C    #Mc   [2] ORIYA VOWEL SIGN O..ORIYA VOWEL SI...
-      | 0xE0 0xAD 0x96          #Mn       ORIYA AI LENGTH MARK
-      | 0xE0 0xAD 0x97          #Mc       ORIYA AU LENGTH MARK
-      | 0xE0 0xAD 0x9C..0x9D    #Lo   [2] ORIYA LETTER RRA..ORIYA LETTER RHA
-      | 0xE0 0xAD 0x9F..0xA1    #Lo   [3] ORIYA LETTER YYA..ORIYA LETTER VOC...
-      | 0xE0 0xAD 0xA2..0xA3    #Mn   [2] ORIYA VOWEL SIGN VOCALIC L..ORIYA ...
-      | 0xE0 0xAD 0xB1          #Lo       ORIYA LETTER WA
-      | 0xE0 0xAE 0x82          #Mn       TAMIL SIGN ANUSVARA
-      | 0xE0 0xAE 0x83          #Lo       TAMIL SIGN VISARGA
-      | 0xE0 0xAE 0x85..0x8A    #Lo   [6] TAMIL LETTER A..TAMIL LETTER UU
-      | 0xE0 0xAE 0x8E..0x90    #Lo   [3] TAMIL LETTER E..TAMIL LETTER AI
-      | 0xE0 0xAE 0x92..0x95    #Lo   [4] TAMIL LETTER O..TAMIL LETTER KA
-      | 0xE0 0xAE 0x99..0x9A    #Lo   [2] TAMIL LETTER NGA..TAMIL LETTER CA
-      | 0xE0 0xAE 0x9C          #Lo       TAMIL LETTER JA
-      | 0xE0 0xAE 0x9E..0x9F    #Lo   [2] TAMIL LETTER NYA..TAMIL LETTER TTA
-      | 0xE0 0xAE 0xA3..0xA4    #Lo   [2] TAMIL LETTER NNA..TAMIL LETTER TA
-      | 0xE0 0xAE 0xA8..0xAA    #Lo   [3] TAMIL LETTER NA..TAMIL LETTER PA
-      | 0xE0 0xAE 0xAE..0xB9    #Lo  [12] TAMIL LETTER MA..TAMIL LETTER HA
-      | 0xE0 0xAE 0xBE..0xBF    #Mc   [2] TAMIL VOWEL SIGN AA..TAMIL VOWEL S...
-      | 0xE0 0xAF 0x80          #Mn       TAMIL VOWEL SIGN II
-      | 0xE0 0xAF 0x81..0x82    #Mc   [2] TAMIL VOWEL SIGN U..TAMIL VOWEL SI...
-      | 0xE0 0xAF 0x86..0x88    #Mc   [3] TAMIL VOWEL SIGN E..TAMIL VOWEL SI...
-      | 0xE0 0xAF 0x8A..0x8C    #Mc   [3] TAMIL VOWEL SIGN O..TAMIL VOWEL SI...
-      | 0xE0 0xAF 0x90          #Lo       TAMIL OM
-      | 0xE0 0xAF 0x97          #Mc       TAMIL AU LENGTH MARK
-      | 0xE0 0xB0 0x81..0x83    #Mc   [3] TELUGU SIGN CANDRABINDU..TELUGU SI...
-      | 0xE0 0xB0 0x85..0x8C    #Lo   [8] TELUGU LETTER A..TELUGU LETTER VOC...
-      | 0xE0 0xB0 0x8E..0x90    #Lo   [3] TELUGU LETTER E..TELUGU LETTER AI
-      | 0xE0 0xB0 0x92..0xA8    #Lo  [23] TELUGU LETTER O..TELUGU LETTER NA
-      | 0xE0 0xB0 0xAA..0xB3    #Lo  [10] TELUGU LETTER PA..TELUGU LETTER LLA
-      | 0xE0 0xB0 0xB5..0xB9    #Lo   [5] TELUGU LETTER VA..TELUGU LETTER HA
-      | 0xE0 0xB0 0xBD          #Lo       TELUGU SIGN AVAGRAHA
-      | 0xE0 0xB0 0xBE..0xFF    #Mn   [3] TELUGU VOWEL SIGN AA..TELUGU VOWEL...
-      | 0xE0 0xB1 0x00..0x80    #
-      | 0xE0 0xB1 0x81..0x84    #Mc   [4] TELUGU VOWEL SIGN U..TELUGU VOWEL ...
-      | 0xE0 0xB1 0x86..0x88    #Mn   [3] TELUGU VOWEL SIGN E..TELUGU VOWEL ...
-      | 0xE0 0xB1 0x8A..0x8C    #Mn   [3] TELUGU VOWEL SIGN O..TELUGU VOWEL ...
-      | 0xE0 0xB1 0x95..0x96    #Mn   [2] TELUGU LENGTH MARK..TELUGU AI LENG...
-      | 0xE0 0xB1 0x98..0x99    #Lo   [2] TELUGU LETTER TSA..TELUGU LETTER DZA
-      | 0xE0 0xB1 0xA0..0xA1    #Lo   [2] TELUGU LETTER VOCALIC RR..TELUGU L...
-      | 0xE0 0xB1 0xA2..0xA3    #Mn   [2] TELUGU VOWEL SIGN VOCALIC L..TELUG...
-      | 0xE0 0xB2 0x82..0x83    #Mc   [2] KANNADA SIGN ANUSVARA..KANNADA SIG...
-      | 0xE0 0xB2 0x85..0x8C    #Lo   [8] KANNADA LETTER A..KANNADA LETTER V...
-      | 0xE0 0xB2 0x8E..0x90    #Lo   [3] KANNADA LETTER E..KANNADA LETTER AI
-      | 0xE0 0xB2 0x92..0xA8    #Lo  [23] KANNADA LETTER O..KANNADA LETTER NA
-      | 0xE0 0xB2 0xAA..0xB3    #Lo  [10] KANNADA LETTER PA..KANNADA LETTER LLA
-      | 0xE0 0xB2 0xB5..0xB9    #Lo   [5] KANNADA LETTER VA..KANNADA LETTER HA
-      | 0xE0 0xB2 0xBD          #Lo       KANNADA SIGN AVAGRAHA
-      | 0xE0 0xB2 0xBE          #Mc       KANNADA VOWEL SIGN AA
-      | 0xE0 0xB2 0xBF          #Mn       KANNADA VOWEL SIGN I
-      | 0xE0 0xB3 0x80..0x84    #Mc   [5] KANNADA VOWEL SIGN II..KANNADA VOW...
-      | 0xE0 0xB3 0x86          #Mn       KANNADA VOWEL SIGN E
-      | 0xE0 0xB3 0x87..0x88    #Mc   [2] KANNADA VOWEL SIGN EE..KANNADA VOW...
-      | 0xE0 0xB3 0x8A..0x8B    #Mc   [2] KANNADA VOWEL SIGN O..KANNADA VOWE...
-      | 0xE0 0xB3 0x8C          #Mn       KANNADA VOWEL SIGN AU
-      | 0xE0 0xB3 0x95..0x96    #Mc   [2] KANNADA LENGTH MARK..KANNADA AI LE...
-      | 0xE0 0xB3 0x9E          #Lo       KANNADA LETTER FA
-      | 0xE0 0xB3 0xA0..0xA1    #Lo   [2] KANNADA LETTER VOCALIC RR..KANNADA...
-      | 0xE0 0xB3 0xA2..0xA3    #Mn   [2] KANNADA VOWEL SIGN VOCALIC L..KANN...
-      | 0xE0 0xB4 0x82..0x83    #Mc   [2] MALAYALAM SIGN ANUSVARA..MALAYALAM...
-      | 0xE0 0xB4 0x85..0x8C    #Lo   [8] MALAYALAM LETTER A..MALAYALAM LETT...
-      | 0xE0 0xB4 0x8E..0x90    #Lo   [3] MALAYALAM LETTER E..MALAYALAM LETT...
-      | 0xE0 0xB4 0x92..0xA8    #Lo  [23] MALAYALAM LETTER O..MALAYALAM LETT...
-      | 0xE0 0xB4 0xAA..0xB9    #Lo  [16] MALAYALAM LETTER PA..MALAYALAM LET...
-      | 0xE0 0xB4 0xBD          #Lo       MALAYALAM SIGN AVAGRAHA
-      | 0xE0 0xB4 0xBE..0xFF    #Mc   [3] MALAYALAM VOWEL SIGN AA..MALAYALAM...
-      | 0xE0 0xB5 0x00..0x80    #
-      | 0xE0 0xB5 0x81..0x84    #Mn   [4] MALAYALAM VOWEL SIGN U..MALAYALAM ...
-      | 0xE0 0xB5 0x86..0x88    #Mc   [3] MALAYALAM VOWEL SIGN E..MALAYALAM ...
-      | 0xE0 0xB5 0x8A..0x8C    #Mc   [3] MALAYALAM VOWEL SIGN O..MALAYALAM ...
-      | 0xE0 0xB5 0x97          #Mc       MALAYALAM AU LENGTH MARK
-      | 0xE0 0xB5 0xA0..0xA1    #Lo   [2] MALAYALAM LETTER VOCALIC RR..MALAY...
-      | 0xE0 0xB5 0xA2..0xA3    #Mn   [2] MALAYALAM VOWEL SIGN VOCALIC L..MA...
-      | 0xE0 0xB5 0xBA..0xBF    #Lo   [6] MALAYALAM LETTER CHILLU NN..MALAYA...
-      | 0xE0 0xB6 0x82..0x83    #Mc   [2] SINHALA SIGN ANUSVARAYA..SINHALA S...
-      | 0xE0 0xB6 0x85..0x96    #Lo  [18] SINHALA LETTER AYANNA..SINHALA LET...
-      | 0xE0 0xB6 0x9A..0xB1    #Lo  [24] SINHALA LETTER ALPAPRAANA KAYANNA....
-      | 0xE0 0xB6 0xB3..0xBB    #Lo   [9] SINHALA LETTER SANYAKA DAYANNA..SI...
-      | 0xE0 0xB6 0xBD          #Lo       SINHALA LETTER DANTAJA LAYANNA
-      | 0xE0 0xB7 0x80..0x86    #Lo   [7] SINHALA LETTER VAYANNA..SINHALA LE...
-      | 0xE0 0xB7 0x8F..0x91    #Mc   [3] SINHALA VOWEL SIGN AELA-PILLA..SIN...
-      | 0xE0 0xB7 0x92..0x94    #Mn   [3] SINHALA VOWEL SIGN KETTI IS-PILLA....
-      | 0xE0 0xB7 0x96          #Mn       SINHALA VOWEL SIGN DIGA PAA-PILLA
-      | 0xE0 0xB7 0x98..0x9F    #Mc   [8] SINHALA VOWEL SIGN GAETTA-PILLA..S...
-      | 0xE0 0xB7 0xB2..0xB3    #Mc   [2] SINHALA VOWEL SIGN DIGA GAETTA-PIL...
-      | 0xE0 0xB8 0x81..0xB0    #Lo  [48] THAI CHARACTER KO KAI..THAI CHARAC...
-      | 0xE0 0xB8 0xB1          #Mn       THAI CHARACTER MAI HAN-AKAT
-      | 0xE0 0xB8 0xB2..0xB3    #Lo   [2] THAI CHARACTER SARA AA..THAI CHARA...
-      | 0xE0 0xB8 0xB4..0xBA    #Mn   [7] THAI CHARACTER SARA I..THAI CHARAC...
-      | 0xE0 0xB9 0x80..0x85    #Lo   [6] THAI CHARACTER SARA E..THAI CHARAC...
-      | 0xE0 0xB9 0x86          #Lm       THAI CHARACTER MAIYAMOK
-      | 0xE0 0xB9 0x8D          #Mn       THAI CHARACTER NIKHAHIT
-      | 0xE0 0xBA 0x81..0x82    #Lo   [2] LAO LETTER KO..LAO LETTER KHO SUNG
-      | 0xE0 0xBA 0x84          #Lo       LAO LETTER KHO TAM
-      | 0xE0 0xBA 0x87..0x88    #Lo   [2] LAO LETTER NGO..LAO LETTER CO
-      | 0xE0 0xBA 0x8A          #Lo       LAO LETTER SO TAM
-      | 0xE0 0xBA 0x8D          #Lo       LAO LETTER NYO
-      | 0xE0 0xBA 0x94..0x97    #Lo   [4] LAO LETTER DO..LAO LETTER THO TAM
-      | 0xE0 0xBA 0x99..0x9F    #Lo   [7] LAO LETTER NO..LAO LETTER FO SUNG
-      | 0xE0 0xBA 0xA1..0xA3    #Lo   [3] LAO LETTER MO..LAO LETTER LO LING
-      | 0xE0 0xBA 0xA5          #Lo       LAO LETTER LO LOOT
-      | 0xE0 0xBA 0xA7          #Lo       LAO LETTER WO
-      | 0xE0 0xBA 0xAA..0xAB    #Lo   [2] LAO LETTER SO SUNG..LAO LETTER HO ...
-      | 0xE0 0xBA 0xAD..0xB0    #Lo   [4] LAO LETTER O..LAO VOWEL SIGN A
-      | 0xE0 0xBA 0xB1          #Mn       LAO VOWEL SIGN MAI KAN
-      | 0xE0 0xBA 0xB2..0xB3    #Lo   [2] LAO VOWEL SIGN AA..LAO VOWEL SIGN AM
-      | 0xE0 0xBA 0xB4..0xB9    #Mn   [6] LAO VOWEL SIGN I..LAO VOWEL SIGN UU
-      | 0xE0 0xBA 0xBB..0xBC    #Mn   [2] LAO VOWEL SIGN MAI KON..LAO SEMIVO...
-      | 0xE0 0xBA 0xBD          #Lo       LAO SEMIVOWEL SIGN NYO
-      | 0xE0 0xBB 0x80..0x84    #Lo   [5] LAO VOWEL SIGN E..LAO VOWEL SIGN AI
-      | 0xE0 0xBB 0x86          #Lm       LAO KO LA
-      | 0xE0 0xBB 0x8D          #Mn       LAO NIGGAHITA
-      | 0xE0 0xBB 0x9C..0x9D    #Lo   [2] LAO HO NO..LAO HO MO
-      | 0xE0 0xBC 0x80          #Lo       TIBETAN SYLLABLE OM
-      | 0xE0 0xBD 0x80..0x87    #Lo   [8] TIBETAN LETTER KA..TIBETAN LETTER JA
-      | 0xE0 0xBD 0x89..0xAC    #Lo  [36] TIBETAN LETTER NYA..TIBETAN LETTER...
-      | 0xE0 0xBD 0xB1..0xBE    #Mn  [14] TIBETAN VOWEL SIGN AA..TIBETAN SIG...
-      | 0xE0 0xBD 0xBF          #Mc       TIBETAN SIGN RNAM BCAD
-      | 0xE0 0xBE 0x80..0x81    #Mn   [2] TIBETAN VOWEL SIGN REVERSED I..TIB...
-      | 0xE0 0xBE 0x88..0x8B    #Lo   [4] TIBETAN SIGN LCE TSA CAN..TIBETAN ...
-      | 0xE0 0xBE 0x90..0x97    #Mn   [8] TIBETAN SUBJOINED LETTER KA..TIBET...
-      | 0xE0 0xBE 0x99..0xBC    #Mn  [36] TIBETAN SUBJOINED LETTER NYA..TIBE...
-      | 0xE1 0x80 0x80..0xAA    #Lo  [43] MYANMAR LETTER KA..MYANMAR LETTER AU
-      | 0xE1 0x80 0xAB..0xAC    #Mc   [2] MYANMAR VOWEL SIGN TALL AA..MYANMA...
-      | 0xE1 0x80 0xAD..0xB0    #Mn   [4] MYANMAR VOWEL SIGN I..MYANMAR VOWE...
-      | 0xE1 0x80 0xB1          #Mc       MYANMAR VOWEL SIGN E
-      | 0xE1 0x80 0xB2..0xB6    #Mn   [5] MYANMAR VOWEL SIGN AI..MYANMAR SIG...
-      | 0xE1 0x80 0xB8          #Mc       MYANMAR SIGN VISARGA
-      | 0xE1 0x80 0xBB..0xBC    #Mc   [2] MYANMAR CONSONANT SIGN MEDIAL YA.....
-      | 0xE1 0x80 0xBD..0xBE    #Mn   [2] MYANMAR CONSONANT SIGN MEDIAL WA.....
-      | 0xE1 0x80 0xBF          #Lo       MYANMAR LETTER GREAT SA
-      | 0xE1 0x81 0x90..0x95    #Lo   [6] MYANMAR LETTER SHA..MYANMAR LETTER...
-      | 0xE1 0x81 0x96..0x97    #Mc   [2] MYANMAR VOWEL SIGN VOCALIC R..MYAN...
-      | 0xE1 0x81 0x98..0x99    #Mn   [2] MYANMAR VOWEL SIGN VOCALIC L..MYAN...
-      | 0xE1 0x81 0x9A..0x9D    #Lo   [4] MYANMAR LETTER MON NGA..MYANMAR LE...
-      | 0xE1 0x81 0x9E..0xA0    #Mn   [3] MYANMAR CONSONANT SIGN MON MEDIAL ...
-      | 0xE1 0x81 0xA1          #Lo       MYANMAR LETTER SGAW KAREN SHA
-      | 0xE1 0x81 0xA2          #Mc       MYANMAR VOWEL SIGN SGAW KAREN EU
-      | 0xE1 0x81 0xA5..0xA6    #Lo   [2] MYANMAR LETTER WESTERN PWO KAREN T...
-      | 0xE1 0x81 0xA7..0xA8    #Mc   [2] MYANMAR VOWEL SIGN WESTERN PWO KAR...
-      | 0xE1 0x81 0xAE..0xB0    #Lo   [3] MYANMAR LETTER EASTERN PWO KAREN N...
-      | 0xE1 0x81 0xB1..0xB4    #Mn   [4] MYANMAR VOWEL SIGN GEBA KAREN I..M...
-      | 0xE1 0x81 0xB5..0xFF    #Lo  [13] MYANMAR LETTER SHAN KA..MYANMAR LE...
-      | 0xE1 0x82 0x00..0x81    #
-      | 0xE1 0x82 0x82          #Mn       MYANMAR CONSONANT SIGN SHAN MEDIAL WA
-      | 0xE1 0x82 0x83..0x84    #Mc   [2] MYANMAR VOWEL SIGN SHAN AA..MYANMA...
-      | 0xE1 0x82 0x85..0x86    #Mn   [2] MYANMAR VOWEL SIGN SHAN E ABOVE..M...
-      | 0xE1 0x82 0x8E          #Lo       MYANMAR LETTER RUMAI PALAUNG FA
-      | 0xE1 0x82 0xA0..0xFF    #L&  [38] GEORGIAN CAPITAL LETTER AN..GEORGI...
-      | 0xE1 0x83 0x00..0x85    #
-      | 0xE1 0x83 0x90..0xBA    #Lo  [43] GEORGIAN LETTER AN..GEORGIAN LETTE...
-      | 0xE1 0x83 0xBC          #Lm       MODIFIER LETTER GEORGIAN NAR
-      | 0xE1 0x84 0x80..0xFF    #Lo  [90] HANGUL CHOSEONG KIYEOK..HANGUL CHO...
-      | 0xE1 0x85 0x00..0x99    #
-      | 0xE1 0x85 0x9F..0xFF    #Lo  [68] HANGUL CHOSEONG FILLER..HANGUL JUN...
-      | 0xE1 0x86 0x00..0xA2    #
-      | 0xE1 0x86 0xA8..0xFF    #Lo  [82] HANGUL JONGSEONG KIYEOK..HANGUL JO...
-      | 0xE1 0x87 0x00..0xB9    #
-      | 0xE1 0x88 0x80..0xFF    #Lo  [73] ETHIOPIC SYLLABLE HA..ETHIOPIC SYL...
-      | 0xE1 0x89 0x00..0x88    #
-      | 0xE1 0x89 0x8A..0x8D    #Lo   [4] ETHIOPIC SYLLABLE QWI..ETHIOPIC SY...
-      | 0xE1 0x89 0x90..0x96    #Lo   [7] ETHIOPIC SYLLABLE QHA..ETHIOPIC SY...
-      | 0xE1 0x89 0x98          #Lo       ETHIOPIC SYLLABLE QHWA
-      | 0xE1 0x89 0x9A..0x9D    #Lo   [4] ETHIOPIC SYLLABLE QHWI..ETHIOPIC S...
-      | 0xE1 0x89 0xA0..0xFF    #Lo  [41] ETHIOPIC SYLLABLE BA..ETHIOPIC SYL...
-      | 0xE1 0x8A 0x00..0x88    #
-      | 0xE1 0x8A 0x8A..0x8D    #Lo   [4] ETHIOPIC SYLLABLE XWI..ETHIOPIC SY...
-      | 0xE1 0x8A 0x90..0xB0    #Lo  [33] ETHIOPIC SYLLABLE NA..ETHIOPIC SYL...
-      | 0xE1 0x8A 0xB2..0xB5    #Lo   [4] ETHIOPIC SYLLABLE KWI..ETHIOPIC SY...
-      | 0xE1 0x8A 0xB8..0xBE    #Lo   [7] ETHIOPIC SYLLABLE KXA..ETHIOPIC SY...
-      | 0xE1 0x8B 0x80          #Lo       ETHIOPIC SYLLABLE KXWA
-      | 0xE1 0x8B 0x82..0x85    #Lo   [4] ETHIOPIC SYLLABLE KXWI..ETHIOPIC S...
-      | 0xE1 0x8B 0x88..0x96    #Lo  [15] ETHIOPIC SYLLABLE WA..ETHIOPIC SYL...
-      | 0xE1 0x8B 0x98..0xFF    #Lo  [57] ETHIOPIC SYLLABLE ZA..ETHIOPIC SYL...
-      | 0xE1 0x8C 0x00..0x90    #
-      | 0xE1 0x8C 0x92..0x95    #Lo   [4] ETHIOPIC SYLLABLE GWI..ETHIOPIC SY...
-      | 0xE1 0x8C 0x98..0xFF    #Lo  [67] ETHIOPIC SYLLABLE GGA..ETHIOPIC SY...
-      | 0xE1 0x8D 0x00..0x9A    #
-      | 0xE1 0x8D 0x9F          #Mn       ETHIOPIC COMBINING GEMINATION MARK
-      | 0xE1 0x8E 0x80..0x8F    #Lo  [16] ETHIOPIC SYLLABLE SEBATBEIT MWA..E...
-      | 0xE1 0x8E 0xA0..0xFF    #Lo  [85] CHEROKEE LETTER A..CHEROKEE LETTER YV
-      | 0xE1 0x8F 0x00..0xB4    #
-      | 0xE1 0x90 0x81..0xFF        #Lo [620] CANADIAN SYLLABICS E..CANADIAN...
-      | 0xE1 0x91..0x98 0x00..0xFF  #
-      | 0xE1 0x99 0x00..0xAC        #
-      | 0xE1 0x99 0xAF..0xB6    #Lo   [8] CANADIAN SYLLABICS QAI..CANADIAN S...
-      | 0xE1 0x9A 0x81..0x9A    #Lo  [26] OGHAM LETTER BEITH..OGHAM LETTER P...
-      | 0xE1 0x9A 0xA0..0xFF    #Lo  [75] RUNIC LETTER FEHU FEOH FE F..RUNIC...
-      | 0xE1 0x9B 0x00..0xAA    #
-      | 0xE1 0x9B 0xAE..0xB0    #Nl   [3] RUNIC ARLAUG SYMBOL..RUNIC BELGTHO...
-      | 0xE1 0x9C 0x80..0x8C    #Lo  [13] TAGALOG LETTER A..TAGALOG LETTER YA
-      | 0xE1 0x9C 0x8E..0x91    #Lo   [4] TAGALOG LETTER LA..TAGALOG LETTER HA
-      | 0xE1 0x9C 0x92..0x93    #Mn   [2] TAGALOG VOWEL SIGN I..TAGALOG VOWE...
-      | 0xE1 0x9C 0xA0..0xB1    #Lo  [18] HANUNOO LETTER A..HANUNOO LETTER HA
-      | 0xE1 0x9C 0xB2..0xB3    #Mn   [2] HANUNOO VOWEL SIGN I..HANUNOO VOWE...
-      | 0xE1 0x9D 0x80..0x91    #Lo  [18] BUHID LETTER A..BUHID LETTER HA
-      | 0xE1 0x9D 0x92..0x93    #Mn   [2] BUHID VOWEL SIGN I..BUHID VOWEL SI...
-      | 0xE1 0x9D 0xA0..0xAC    #Lo  [13] TAGBANWA LETTER A..TAGBANWA LETTER YA
-      | 0xE1 0x9D 0xAE..0xB0    #Lo   [3] TAGBANWA LETTER LA..TAGBANWA LETTE...
-      | 0xE1 0x9D 0xB2..0xB3    #Mn   [2] TAGBANWA VOWEL SIGN I..TAGBANWA VO...
-      | 0xE1 0x9E 0x80..0xB3    #Lo  [52] KHMER LETTER KA..KHMER INDEPENDENT...
-      | 0xE1 0x9E 0xB6          #Mc       KHMER VOWEL SIGN AA
-      | 0xE1 0x9E 0xB7..0xBD    #Mn   [7] KHMER VOWEL SIGN I..KHMER VOWEL SI...
-      | 0xE1 0x9E 0xBE..0xFF    #Mc   [8] KHMER VOWEL SIGN OE..KHMER VOWEL S...
-      | 0xE1 0x9F 0x00..0x85    #
-      | 0xE1 0x9F 0x86          #Mn       KHMER SIGN NIKAHIT
-      | 0xE1 0x9F 0x87..0x88    #Mc   [2] KHMER SIGN REAHMUK..KHMER SIGN YUU...
-      | 0xE1 0x9F 0x97          #Lm       KHMER SIGN LEK TOO
-      | 0xE1 0x9F 0x9C          #Lo       KHMER SIGN AVAKRAHASANYA
-      | 0xE1 0xA0 0xA0..0xFF    #Lo  [35] MONGOLIAN LETTER A..MONGOLIAN LETT...
-      | 0xE1 0xA1 0x00..0x82    #
-      | 0xE1 0xA1 0x83          #Lm       MONGOLIAN LETTER TODO LONG VOWEL SIGN
-      | 0xE1 0xA1 0x84..0xB7    #Lo  [52] MONGOLIAN LETTER TODO E..MONGOLIAN...
-      | 0xE1 0xA2 0x80..0xA8    #Lo  [41] MONGOLIAN LETTER ALI GALI ANUSVARA...
-      | 0xE1 0xA2 0xA9          #Mn       MONGOLIAN LETTER ALI GALI DAGALGA
-      | 0xE1 0xA2 0xAA          #Lo       MONGOLIAN LETTER MANCHU ALI GALI LHA
-      | 0xE1 0xA4 0x80..0x9C    #Lo  [29] LIMBU VOWEL-CARRIER LETTER..LIMBU ...
-      | 0xE1 0xA4 0xA0..0xA2    #Mn   [3] LIMBU VOWEL SIGN A..LIMBU VOWEL SI...
-      | 0xE1 0xA4 0xA3..0xA6    #Mc   [4] LIMBU VOWEL SIGN EE..LIMBU VOWEL S...
-      | 0xE1 0xA4 0xA7..0xA8    #Mn   [2] LIMBU VOWEL SIGN E..LIMBU VOWEL SI...
-      | 0xE1 0xA4 0xA9..0xAB    #Mc   [3] LIMBU SUBJOINED LETTER YA..LIMBU S...
-      | 0xE1 0xA4 0xB0..0xB1    #Mc   [2] LIMBU SMALL LETTER KA..LIMBU SMALL...
-      | 0xE1 0xA4 0xB2          #Mn       LIMBU SMALL LETTER ANUSVARA
-      | 0xE1 0xA4 0xB3..0xB8    #Mc   [6] LIMBU SMALL LETTER TA..LIMBU SMALL...
-      | 0xE1 0xA5 0x90..0xAD    #Lo  [30] TAI LE LETTER KA..TAI LE LETTER AI
-      | 0xE1 0xA5 0xB0..0xB4    #Lo   [5] TAI LE LETTER TONE-2..TAI LE LETTE...
-      | 0xE1 0xA6 0x80..0xA9    #Lo  [42] NEW TAI LUE LETTER HIGH QA..NEW TA...
-      | 0xE1 0xA6 0xB0..0xFF    #Mc  [17] NEW TAI LUE VOWEL SIGN VOWEL SHORT...
-      | 0xE1 0xA7 0x00..0x80    #
-      | 0xE1 0xA7 0x81..0x87    #Lo   [7] NEW TAI LUE LETTER FINAL V..NEW TA...
-      | 0xE1 0xA7 0x88..0x89    #Mc   [2] NEW TAI LUE TONE MARK-1..NEW TAI L...
-      | 0xE1 0xA8 0x80..0x96    #Lo  [23] BUGINESE LETTER KA..BUGINESE LETTE...
-      | 0xE1 0xA8 0x97..0x98    #Mn   [2] BUGINESE VOWEL SIGN I..BUGINESE VO...
-      | 0xE1 0xA8 0x99..0x9B    #Mc   [3] BUGINESE VOWEL SIGN E..BUGINESE VO...
-      | 0xE1 0xAC 0x80..0x83    #Mn   [4] BALINESE SIGN ULU RICEM..BALINESE ...
-      | 0xE1 0xAC 0x84          #Mc       BALINESE SIGN BISAH
-      | 0xE1 0xAC 0x85..0xB3    #Lo  [47] BALINESE LETTER AKARA..BALINESE LE...
-      | 0xE1 0xAC 0xB5          #Mc       BALINESE VOWEL SIGN TEDUNG
-      | 0xE1 0xAC 0xB6..0xBA    #Mn   [5] BALINESE VOWEL SIGN ULU..BALINESE ...
-      | 0xE1 0xAC 0xBB          #Mc       BALINESE VOWEL SIGN RA REPA TEDUNG
-      | 0xE1 0xAC 0xBC          #Mn       BALINESE VOWEL SIGN LA LENGA
-      | 0xE1 0xAC 0xBD..0xFF    #Mc   [5] BALINESE VOWEL SIGN LA LENGA TEDUN...
-      | 0xE1 0xAD 0x00..0x81    #
-      | 0xE1 0xAD 0x82          #Mn       BALINESE VOWEL SIGN PEPET
-      | 0xE1 0xAD 0x83          #Mc       BALINESE VOWEL SIGN PEPET TEDUNG
-      | 0xE1 0xAD 0x85..0x8B    #Lo   [7] BALINESE LETTER KAF SASAK..BALINES...
-      | 0xE1 0xAE 0x80..0x81    #Mn   [2] SUNDANESE SIGN PANYECEK..SUNDANESE...
-      | 0xE1 0xAE 0x82          #Mc       SUNDANESE SIGN PANGWISAD
-      | 0xE1 0xAE 0x83..0xA0    #Lo  [30] SUNDANESE LETTER A..SUNDANESE LETT...
-      | 0xE1 0xAE 0xA1          #Mc       SUNDANESE CONSONANT SIGN PAMINGKAL
-      | 0xE1 0xAE 0xA2..0xA5    #Mn   [4] SUNDANESE CONSONANT SIGN PANYAKRA....
-      | 0xE1 0xAE 0xA6..0xA7    #Mc   [2] SUNDANESE VOWEL SIGN PANAELAENG..S...
-      | 0xE1 0xAE 0xA8..0xA9    #Mn   [2] SUNDANESE VOWEL SIGN PAMEPET..SUND...
-      | 0xE1 0xAE 0xAE..0xAF    #Lo   [2] SUNDANESE LETTER KHA..SUNDANESE LE...
-      | 0xE1 0xB0 0x80..0xA3    #Lo  [36] LEPCHA LETTER KA..LEPCHA LETTER A
-      | 0xE1 0xB0 0xA4..0xAB    #Mc   [8] LEPCHA SUBJOINED LETTER YA..LEPCHA...
-      | 0xE1 0xB0 0xAC..0xB3    #Mn   [8] LEPCHA VOWEL SIGN E..LEPCHA CONSON...
-      | 0xE1 0xB0 0xB4..0xB5    #Mc   [2] LEPCHA CONSONANT SIGN NYIN-DO..LEP...
-      | 0xE1 0xB1 0x8D..0x8F    #Lo   [3] LEPCHA LETTER TTA..LEPCHA LETTER DDA
-      | 0xE1 0xB1 0x9A..0xB7    #Lo  [30] OL CHIKI LETTER LA..OL CHIKI LETTE...
-      | 0xE1 0xB1 0xB8..0xBD    #Lm   [6] OL CHIKI MU TTUDDAG..OL CHIKI AHAD
-      | 0xE1 0xB4 0x80..0xAB    #L&  [44] LATIN LETTER SMALL CAPITAL A..CYRI...
-      | 0xE1 0xB4 0xAC..0xFF    #Lm  [54] MODIFIER LETTER CAPITAL A..MODIFIE...
-      | 0xE1 0xB5 0x00..0xA1    #
-      | 0xE1 0xB5 0xA2..0xB7    #L&  [22] LATIN SUBSCRIPT SMALL LETTER I..LA...
-      | 0xE1 0xB5 0xB8          #Lm       MODIFIER LETTER CYRILLIC EN
-      | 0xE1 0xB5 0xB9..0xFF    #L&  [34] LATIN SMALL LETTER INSULAR G..LATI...
-      | 0xE1 0xB6 0x00..0x9A    #
-      | 0xE1 0xB6 0x9B..0xBF    #Lm  [37] MODIFIER LETTER SMALL TURNED ALPHA...
-      | 0xE1 0xB8 0x80..0xFF        #L& [278] LATIN CAPITAL LETTER A WITH RI...
-      | 0xE1 0xB9..0xBB 0x00..0xFF  #
-      | 0xE1 0xBC 0x00..0x95        #
-      | 0xE1 0xBC 0x98..0x9D    #L&   [6] GREEK CAPITAL LETTER EPSILON WITH ...
-      | 0xE1 0xBC 0xA0..0xFF    #L&  [38] GREEK SMALL LETTER ETA WITH PSILI....
-      | 0xE1 0xBD 0x00..0x85    #
-      | 0xE1 0xBD 0x88..0x8D    #L&   [6] GREEK CAPITAL LETTER OMICRON WITH ...
-      | 0xE1 0xBD 0x90..0x97    #L&   [8] GREEK SMALL LETTER UPSILON WITH PS...
-      | 0xE1 0xBD 0x99          #L&       GREEK CAPITAL LETTER UPSILON WITH ...
-      | 0xE1 0xBD 0x9B          #L&       GREEK CAPITAL LETTER UPSILON WITH ...
-      | 0xE1 0xBD 0x9D          #L&       GREEK CAPITAL LETTER UPSILON WITH ...
-      | 0xE1 0xBD 0x9F..0xBD    #L&  [31] GREEK CAPITAL LETTER UPSILON WITH ...
-      | 0xE1 0xBE 0x80..0xB4    #L&  [53] GREEK SMALL LETTER ALPHA WITH PSIL...
-      | 0xE1 0xBE 0xB6..0xBC    #L&   [7] GREEK SMALL LETTER ALPHA WITH PERI...
-      | 0xE1 0xBE 0xBE          #L&       GREEK PROSGEGRAMMENI
-      | 0xE1 0xBF 0x82..0x84    #L&   [3] GREEK SMALL LETTER ETA WITH VARIA ...
-      | 0xE1 0xBF 0x86..0x8C    #L&   [7] GREEK SMALL LETTER ETA WITH PERISP...
-      | 0xE1 0xBF 0x90..0x93    #L&   [4] GREEK SMALL LETTER IOTA WITH VRACH...
-      | 0xE1 0xBF 0x96..0x9B    #L&   [6] GREEK SMALL LETTER IOTA WITH PERIS...
-      | 0xE1 0xBF 0xA0..0xAC    #L&  [13] GREEK SMALL LETTER UPSILON WITH VR...
-      | 0xE1 0xBF 0xB2..0xB4    #L&   [3] GREEK SMALL LETTER OMEGA WITH VARI...
-      | 0xE1 0xBF 0xB6..0xBC    #L&   [7] GREEK SMALL LETTER OMEGA WITH PERI...
-      | 0xE2 0x81 0xB1          #L&       SUPERSCRIPT LATIN SMALL LETTER I
-      | 0xE2 0x81 0xBF          #L&       SUPERSCRIPT LATIN SMALL LETTER N
-      | 0xE2 0x82 0x90..0x94    #Lm   [5] LATIN SUBSCRIPT SMALL LETTER A..LA...
-      | 0xE2 0x84 0x82          #L&       DOUBLE-STRUCK CAPITAL C
-      | 0xE2 0x84 0x87          #L&       EULER CONSTANT
-      | 0xE2 0x84 0x8A..0x93    #L&  [10] SCRIPT SMALL G..SCRIPT SMALL L
-      | 0xE2 0x84 0x95          #L&       DOUBLE-STRUCK CAPITAL N
-      | 0xE2 0x84 0x99..0x9D    #L&   [5] DOUBLE-STRUCK CAPITAL P..DOUBLE-ST...
-      | 0xE2 0x84 0xA4          #L&       DOUBLE-STRUCK CAPITAL Z
-      | 0xE2 0x84 0xA6          #L&       OHM SIGN
-      | 0xE2 0x84 0xA8          #L&       BLACK-LETTER CAPITAL Z
-      | 0xE2 0x84 0xAA..0xAD    #L&   [4] KELVIN SIGN..BLACK-LETTER CAPITAL C
-      | 0xE2 0x84 0xAF..0xB4    #L&   [6] SCRIPT SMALL E..SCRIPT SMALL O
-      | 0xE2 0x84 0xB5..0xB8    #Lo   [4] ALEF SYMBOL..DALET SYMBOL
-      | 0xE2 0x84 0xB9          #L&       INFORMATION SOURCE
-      | 0xE2 0x84 0xBC..0xBF    #L&   [4] DOUBLE-STRUCK SMALL PI..DOUBLE-STR...
-      | 0xE2 0x85 0x85..0x89    #L&   [5] DOUBLE-STRUCK ITALIC CAPITAL D..DO...
-      | 0xE2 0x85 0x8E          #L&       TURNED SMALL F
-      | 0xE2 0x85 0xA0..0xFF    #Nl  [35] ROMAN NUMERAL ONE..ROMAN NUMERAL T...
-      | 0xE2 0x86 0x00..0x82    #
-      | 0xE2 0x86 0x83..0x84    #L&   [2] ROMAN NUMERAL REVERSED ONE HUNDRED...
-      | 0xE2 0x86 0x85..0x88    #Nl   [4] ROMAN NUMERAL SIX LATE FORM..ROMAN...
-      | 0xE2 0x92 0xB6..0xFF    #So  [52] CIRCLED LATIN CAPITAL LETTER A..CI...
-      | 0xE2 0x93 0x00..0xA9    #
-      | 0xE2 0xB0 0x80..0xAE    #L&  [47] GLAGOLITIC CAPITAL LETTER AZU..GLA...
-      | 0xE2 0xB0 0xB0..0xFF    #L&  [47] GLAGOLITIC SMALL LETTER AZU..GLAGO...
-      | 0xE2 0xB1 0x00..0x9E    #
-      | 0xE2 0xB1 0xA0..0xAF    #L&  [16] LATIN CAPITAL LETTER L WITH DOUBLE...
-      | 0xE2 0xB1 0xB1..0xBC    #L&  [12] LATIN SMALL LETTER V WITH RIGHT HO...
-      | 0xE2 0xB1 0xBD          #Lm       MODIFIER LETTER CAPITAL V
-      | 0xE2 0xB2 0x80..0xFF    #L& [101] COPTIC CAPITAL LETTER ALFA..COPTIC...
-      | 0xE2 0xB3 0x00..0xA4    #
-      | 0xE2 0xB4 0x80..0xA5    #L&  [38] GEORGIAN SMALL LETTER AN..GEORGIAN...
-      | 0xE2 0xB4 0xB0..0xFF    #Lo  [54] TIFINAGH LETTER YA..TIFINAGH LETTE...
-      | 0xE2 0xB5 0x00..0xA5    #
-      | 0xE2 0xB5 0xAF          #Lm       TIFINAGH MODIFIER LETTER LABIALIZA...
-      | 0xE2 0xB6 0x80..0x96    #Lo  [23] ETHIOPIC SYLLABLE LOA..ETHIOPIC SY...
-      | 0xE2 0xB6 0xA0..0xA6    #Lo   [7] ETHIOPIC SYLLABLE SSA..ETHIOPIC SY...
-      | 0xE2 0xB6 0xA8..0xAE    #Lo   [7] ETHIOPIC SYLLABLE CCA..ETHIOPIC SY...
-      | 0xE2 0xB6 0xB0..0xB6    #Lo   [7] ETHIOPIC SYLLABLE ZZA..ETHIOPIC SY...
-      | 0xE2 0xB6 0xB8..0xBE    #Lo   [7] ETHIOPIC SYLLABLE CCHA..ETHIOPIC S...
-      | 0xE2 0xB7 0x80..0x86    #Lo   [7] ETHIOPIC SYLLABLE QYA..ETHIOPIC SY...
-      | 0xE2 0xB7 0x88..0x8E    #Lo   [7] ETHIOPIC SYLLABLE KYA..ETHIOPIC SY...
-      | 0xE2 0xB7 0x90..0x96    #Lo   [7] ETHIOPIC SYLLABLE XYA..ETHIOPIC SY...
-      | 0xE2 0xB7 0x98..0x9E    #Lo   [7] ETHIOPIC SYLLABLE GYA..ETHIOPIC SY...
-      | 0xE2 0xB7 0xA0..0xBF    #Mn  [32] COMBINING CYRILLIC LETTER BE..COMB...
-      | 0xE2 0xB8 0xAF          #Lm       VERTICAL TILDE
-      | 0xE3 0x80 0x85          #Lm       IDEOGRAPHIC ITERATION MARK
-      | 0xE3 0x80 0x86          #Lo       IDEOGRAPHIC CLOSING MARK
-      | 0xE3 0x80 0x87          #Nl       IDEOGRAPHIC NUMBER ZERO
-      | 0xE3 0x80 0xA1..0xA9    #Nl   [9] HANGZHOU NUMERAL ONE..HANGZHOU NUM...
-      | 0xE3 0x80 0xB1..0xB5    #Lm   [5] VERTICAL KANA REPEAT MARK..VERTICA...
-      | 0xE3 0x80 0xB8..0xBA    #Nl   [3] HANGZHOU NUMERAL TEN..HANGZHOU NUM...
-      | 0xE3 0x80 0xBB          #Lm       VERTICAL IDEOGRAPHIC ITERATION MARK
-      | 0xE3 0x80 0xBC          #Lo       MASU MARK
-      | 0xE3 0x81 0x81..0xFF    #Lo  [86] HIRAGANA LETTER SMALL A..HIRAGANA ...
-      | 0xE3 0x82 0x00..0x96    #
-      | 0xE3 0x82 0x9D..0x9E    #Lm   [2] HIRAGANA ITERATION MARK..HIRAGANA ...
-      | 0xE3 0x82 0x9F          #Lo       HIRAGANA DIGRAPH YORI
-      | 0xE3 0x82 0xA1..0xFF    #Lo  [90] KATAKANA LETTER SMALL A..KATAKANA ...
-      | 0xE3 0x83 0x00..0xBA    #
-      | 0xE3 0x83 0xBC..0xBE    #Lm   [3] KATAKANA-HIRAGANA PROLONGED SOUND ...
-      | 0xE3 0x83 0xBF          #Lo       KATAKANA DIGRAPH KOTO
-      | 0xE3 0x84 0x85..0xAD    #Lo  [41] BOPOMOFO LETTER B..BOPOMOFO LETTER IH
-      | 0xE3 0x84 0xB1..0xFF        #Lo  [94] HANGUL LETTER KIYEOK..HANGUL L...
-      | 0xE3 0x85..0x85 0x00..0xFF  #
-      | 0xE3 0x86 0x00..0x8E        #
-      | 0xE3 0x86 0xA0..0xB7    #Lo  [24] BOPOMOFO LETTER BU..BOPOMOFO FINAL...
-      | 0xE3 0x87 0xB0..0xBF    #Lo  [16] KATAKANA LETTER SMALL KU..KATAKANA...
-      | 0xE3 0x90 0x80..0xFF        #Lo [6582] CJK UNIFIED IDEOGRAPH-3400..C...
-      | 0xE3 0x91..0xFF 0x00..0xFF  #
-      | 0xE4 0x00 0x00..0xFF        #
-      | 0xE4 0x01..0xB5 0x00..0xFF  #
-      | 0xE4 0xB6 0x00..0xB5        #
-      | 0xE4 0xB8 0x80..0xFF              #Lo [20932] CJK UNIFIED IDEOGRAPH-...
-      | 0xE4 0xB9..0xFF 0x00..0xFF        #
-      | 0xE5..0xE8 0x00..0xFF 0x00..0xFF  #
-      | 0xE9 0x00 0x00..0xFF              #
-      | 0xE9 0x01..0xBE 0x00..0xFF        #
-      | 0xE9 0xBF 0x00..0x83              #
-      | 0xEA 0x80 0x80..0x94    #Lo  [21] YI SYLLABLE IT..YI SYLLABLE E
-      | 0xEA 0x80 0x95          #Lm       YI SYLLABLE WU
-      | 0xEA 0x80 0x96..0xFF        #Lo [1143] YI SYLLABLE BIT..YI SYLLABLE YYR
-      | 0xEA 0x81..0x91 0x00..0xFF  #
-      | 0xEA 0x92 0x00..0x8C        #
-      | 0xEA 0x94 0x80..0xFF        #Lo [268] VAI SYLLABLE EE..VAI SYLLABLE NG
-      | 0xEA 0x95..0x97 0x00..0xFF  #
-      | 0xEA 0x98 0x00..0x8B        #
-      | 0xEA 0x98 0x8C          #Lm       VAI SYLLABLE LENGTHENER
-      | 0xEA 0x98 0x90..0x9F    #Lo  [16] VAI SYLLABLE NDOLE FA..VAI SYMBOL ...
-      | 0xEA 0x98 0xAA..0xAB    #Lo   [2] VAI SYLLABLE NDOLE MA..VAI SYLLABL...
-      | 0xEA 0x99 0x80..0x9F    #L&  [32] CYRILLIC CAPITAL LETTER ZEMLYA..CY...
-      | 0xEA 0x99 0xA2..0xAD    #L&  [12] CYRILLIC CAPITAL LETTER SOFT DE..C...
-      | 0xEA 0x99 0xAE          #Lo       CYRILLIC LETTER MULTIOCULAR O
-      | 0xEA 0x99 0xBF          #Lm       CYRILLIC PAYEROK
-      | 0xEA 0x9A 0x80..0x97    #L&  [24] CYRILLIC CAPITAL LETTER DWE..CYRIL...
-      | 0xEA 0x9C 0x97..0x9F    #Lm   [9] MODIFIER LETTER DOT VERTICAL BAR.....
-      | 0xEA 0x9C 0xA2..0xFF    #L&  [78] LATIN CAPITAL LETTER EGYPTOLOGICAL...
-      | 0xEA 0x9D 0x00..0xAF    #
-      | 0xEA 0x9D 0xB0          #Lm       MODIFIER LETTER US
-      | 0xEA 0x9D 0xB1..0xFF    #L&  [23] LATIN SMALL LETTER DUM..LATIN SMAL...
-      | 0xEA 0x9E 0x00..0x87    #
-      | 0xEA 0x9E 0x88          #Lm       MODIFIER LETTER LOW CIRCUMFLEX ACCENT
-      | 0xEA 0x9E 0x8B..0x8C    #L&   [2] LATIN CAPITAL LETTER SALTILLO..LAT...
-      | 0xEA 0x9F 0xBB..0xFF    #Lo   [7] LATIN EPIGRAPHIC LETTER REVERSED F...
-      | 0xEA 0xA0 0x00..0x81    #
-      | 0xEA 0xA0 0x83..0x85    #Lo   [3] SYLOTI NAGRI LETTER U..SYLOTI NAGR...
-      | 0xEA 0xA0 0x87..0x8A    #Lo   [4] SYLOTI NAGRI LETTER KO..SYLOTI NAG...
-      | 0xEA 0xA0 0x8C..0xA2    #Lo  [23] SYLOTI NAGRI LETTER CO..SYLOTI NAG...
-      | 0xEA 0xA0 0xA3..0xA4    #Mc   [2] SYLOTI NAGRI VOWEL SIGN A..SYLOTI ...
-      | 0xEA 0xA0 0xA5..0xA6    #Mn   [2] SYLOTI NAGRI VOWEL SIGN U..SYLOTI ...
-      | 0xEA 0xA0 0xA7          #Mc       SYLOTI NAGRI VOWEL SIGN OO
-      | 0xEA 0xA1 0x80..0xB3    #Lo  [52] PHAGS-PA LETTER KA..PHAGS-PA LETTE...
-      | 0xEA 0xA2 0x80..0x81    #Mc   [2] SAURASHTRA SIGN ANUSVARA..SAURASHT...
-      | 0xEA 0xA2 0x82..0xB3    #Lo  [50] SAURASHTRA LETTER A..SAURASHTRA LE...
-      | 0xEA 0xA2 0xB4..0xFF    #Mc  [16] SAURASHTRA CONSONANT SIGN HAARU..S...
-      | 0xEA 0xA3 0x00..0x83    #
-      | 0xEA 0xA4 0x8A..0xA5    #Lo  [28] KAYAH LI LETTER KA..KAYAH LI LETTE...
-      | 0xEA 0xA4 0xA6..0xAA    #Mn   [5] KAYAH LI VOWEL UE..KAYAH LI VOWEL O
-      | 0xEA 0xA4 0xB0..0xFF    #Lo  [23] REJANG LETTER KA..REJANG LETTER A
-      | 0xEA 0xA5 0x00..0x86    #
-      | 0xEA 0xA5 0x87..0x91    #Mn  [11] REJANG VOWEL SIGN I..REJANG CONSON...
-      | 0xEA 0xA5 0x92          #Mc       REJANG CONSONANT SIGN H
-      | 0xEA 0xA8 0x80..0xA8    #Lo  [41] CHAM LETTER A..CHAM LETTER HA
-      | 0xEA 0xA8 0xA9..0xAE    #Mn   [6] CHAM VOWEL SIGN AA..CHAM VOWEL SIG...
-      | 0xEA 0xA8 0xAF..0xB0    #Mc   [2] CHAM VOWEL SIGN O..CHAM VOWEL SIGN AI
-      | 0xEA 0xA8 0xB1..0xB2    #Mn   [2] CHAM VOWEL SIGN AU..CHAM VOWEL SIG...
-      | 0xEA 0xA8 0xB3..0xB4    #Mc   [2] CHAM CONSONANT SIGN YA..CHAM CONSO...
-      | 0xEA 0xA8 0xB5..0xB6    #Mn   [2] CHAM CONSONANT SIGN LA..CHAM CONSO...
-      | 0xEA 0xA9 0x80..0x82    #Lo   [3] CHAM LETTER FINAL K..CHAM LETTER F...
-      | 0xEA 0xA9 0x83          #Mn       CHAM CONSONANT SIGN FINAL NG
-      | 0xEA 0xA9 0x84..0x8B    #Lo   [8] CHAM LETTER FINAL CH..CHAM LETTER ...
-      | 0xEA 0xA9 0x8C          #Mn       CHAM CONSONANT SIGN FINAL M
-      | 0xEA 0xA9 0x8D          #Mc       CHAM CONSONANT SIGN FINAL H
-      | 0xEA 0xB0 0x80..0xFF              #Lo [11172] HANGUL SYLLABLE GA..HA...
-      | 0xEA 0xB1..0xFF 0x00..0xFF        #
-      | 0xEB..0xEC 0x00..0xFF 0x00..0xFF  #
-      | 0xED 0x00 0x00..0xFF              #
-      | 0xED 0x01..0x9D 0x00..0xFF        #
-      | 0xED 0x9E 0x00..0xA3              #
-      | 0xEF 0xA4 0x80..0xFF        #Lo [302] CJK COMPATIBILITY IDEOGRAPH-F9...
-      | 0xEF 0xA5..0xA7 0x00..0xFF  #
-      | 0xEF 0xA8 0x00..0xAD        #
-      | 0xEF 0xA8 0xB0..0xFF    #Lo  [59] CJK COMPATIBILITY IDEOGRAPH-FA30.....
-      | 0xEF 0xA9 0x00..0xAA    #
-      | 0xEF 0xA9 0xB0..0xFF        #Lo [106] CJK COMPATIBILITY IDEOGRAPH-FA...
-      | 0xEF 0xAA..0xAA 0x00..0xFF  #
-      | 0xEF 0xAB 0x00..0x99        #
-      | 0xEF 0xAC 0x80..0x86    #L&   [7] LATIN SMALL LIGATURE FF..LATIN SMA...
-      | 0xEF 0xAC 0x93..0x97    #L&   [5] ARMENIAN SMALL LIGATURE MEN NOW..A...
-      | 0xEF 0xAC 0x9D          #Lo       HEBREW LETTER YOD WITH HIRIQ
-      | 0xEF 0xAC 0x9E          #Mn       HEBREW POINT JUDEO-SPANISH VARIKA
-      | 0xEF 0xAC 0x9F..0xA8    #Lo  [10] HEBREW LIGATURE YIDDISH YOD YOD PA...
-      | 0xEF 0xAC 0xAA..0xB6    #Lo  [13] HEBREW LETTER SHIN WITH SHIN DOT.....
-      | 0xEF 0xAC 0xB8..0xBC    #Lo   [5] HEBREW LETTER TET WITH DAGESH..HEB...
-      | 0xEF 0xAC 0xBE          #Lo       HEBREW LETTER MEM WITH DAGESH
-      | 0xEF 0xAD 0x80..0x81    #Lo   [2] HEBREW LETTER NUN WITH DAGESH..HEB...
-      | 0xEF 0xAD 0x83..0x84    #Lo   [2] HEBREW LETTER FINAL PE WITH DAGESH...
-      | 0xEF 0xAD 0x86..0xFF    #Lo [108] HEBREW LETTER TSADI WITH DAGESH..A...
-      | 0xEF 0xAE 0x00..0xB1    #
-      | 0xEF 0xAF 0x93..0xFF        #Lo [363] ARABIC LETTER NG ISOLATED FORM...
-      | 0xEF 0xB0..0xB3 0x00..0xFF  #
-      | 0xEF 0xB4 0x00..0xBD        #
-      | 0xEF 0xB5 0x90..0xFF    #Lo  [64] ARABIC LIGATURE TEH WITH JEEM WITH...
-      | 0xEF 0xB6 0x00..0x8F    #
-      | 0xEF 0xB6 0x92..0xFF    #Lo  [54] ARABIC LIGATURE MEEM WITH JEEM WIT...
-      | 0xEF 0xB7 0x00..0x87    #
-      | 0xEF 0xB7 0xB0..0xBB    #Lo  [12] ARABIC LIGATURE SALLA USED AS KORA...
-      | 0xEF 0xB9 0xB0..0xB4    #Lo   [5] ARABIC FATHATAN ISOLATED FORM..ARA...
-      | 0xEF 0xB9 0xB6..0xFF        #Lo [135] ARABIC FATHA ISOLATED FORM..AR...
-      | 0xEF 0xBA..0xBA 0x00..0xFF  #
-      | 0xEF 0xBB 0x00..0xBC        #
-      | 0xEF 0xBC 0xA1..0xBA    #L&  [26] FULLWIDTH LATIN CAPITAL LETTER A.....
-      | 0xEF 0xBD 0x81..0x9A    #L&  [26] FULLWIDTH LATIN SMALL LETTER A..FU...
-      | 0xEF 0xBD 0xA6..0xAF    #Lo  [10] HALFWIDTH KATAKANA LETTER WO..HALF...
-      | 0xEF 0xBD 0xB0          #Lm       HALFWIDTH KATAKANA-HIRAGANA PROLON...
-      | 0xEF 0xBD 0xB1..0xFF    #Lo  [45] HALFWIDTH KATAKANA LETTER A..HALFW...
-      | 0xEF 0xBE 0x00..0x9D    #
-      | 0xEF 0xBE 0x9E..0x9F    #Lm   [2] HALFWIDTH KATAKANA VOICED SOUND MA...
-      | 0xEF 0xBE 0xA0..0xBE    #Lo  [31] HALFWIDTH HANGUL FILLER..HALFWIDTH...
-      | 0xEF 0xBF 0x82..0x87    #Lo   [6] HALFWIDTH HANGUL LETTER A..HALFWID...
-      | 0xEF 0xBF 0x8A..0x8F    #Lo   [6] HALFWIDTH HANGUL LETTER YEO..HALFW...
-      | 0xEF 0xBF 0x92..0x97    #Lo   [6] HALFWIDTH HANGUL LETTER YO..HALFWI...
-      | 0xEF 0xBF 0x9A..0x9C    #Lo   [3] HALFWIDTH HANGUL LETTER EU..HALFWI...
-      | 0xF0 0x90 0x80 0x80..0x8B  #Lo  [12] LINEAR B SYLLABLE B008 A..LINEA...
-      | 0xF0 0x90 0x80 0x8D..0xA6  #Lo  [26] LINEAR B SYLLABLE B036 JO..LINE...
-      | 0xF0 0x90 0x80 0xA8..0xBA  #Lo  [19] LINEAR B SYLLABLE B060 RA..LINE...
-      | 0xF0 0x90 0x80 0xBC..0xBD  #Lo   [2] LINEAR B SYLLABLE B017 ZA..LINE...
-      | 0xF0 0x90 0x80 0xBF..0xFF  #Lo  [15] LINEAR B SYLLABLE B020 ZO..LINE...
-      | 0xF0 0x90 0x81 0x00..0x8D  #
-      | 0xF0 0x90 0x81 0x90..0x9D  #Lo  [14] LINEAR B SYMBOL B018..LINEAR B ...
-      | 0xF0 0x90 0x82 0x80..0xFF  #Lo [123] LINEAR B IDEOGRAM B100 MAN..LIN...
-      | 0xF0 0x90 0x83 0x00..0xBA  #
-      | 0xF0 0x90 0x85 0x80..0xB4  #Nl  [53] GREEK ACROPHONIC ATTIC ONE QUAR...
-      | 0xF0 0x90 0x8A 0x80..0x9C  #Lo  [29] LYCIAN LETTER A..LYCIAN LETTER X
-      | 0xF0 0x90 0x8A 0xA0..0xFF  #Lo  [49] CARIAN LETTER A..CARIAN LETTER ...
-      | 0xF0 0x90 0x8B 0x00..0x90  #
-      | 0xF0 0x90 0x8C 0x80..0x9E  #Lo  [31] OLD ITALIC LETTER A..OLD ITALIC...
-      | 0xF0 0x90 0x8C 0xB0..0xFF  #Lo  [17] GOTHIC LETTER AHSA..GOTHIC LETT...
-      | 0xF0 0x90 0x8D 0x00..0x80  #
-      | 0xF0 0x90 0x8D 0x81     #Nl       GOTHIC LETTER NINETY
-      | 0xF0 0x90 0x8D 0x82..0x89  #Lo   [8] GOTHIC LETTER RAIDA..GOTHIC LET...
-      | 0xF0 0x90 0x8D 0x8A     #Nl       GOTHIC LETTER NINE HUNDRED
-      | 0xF0 0x90 0x8E 0x80..0x9D  #Lo  [30] UGARITIC LETTER ALPA..UGARITIC ...
-      | 0xF0 0x90 0x8E 0xA0..0xFF  #Lo  [36] OLD PERSIAN SIGN A..OLD PERSIAN...
-      | 0xF0 0x90 0x8F 0x00..0x83  #
-      | 0xF0 0x90 0x8F 0x88..0x8F  #Lo   [8] OLD PERSIAN SIGN AURAMAZDAA..OL...
-      | 0xF0 0x90 0x8F 0x91..0x95  #Nl   [5] OLD PERSIAN NUMBER ONE..OLD PER...
-      | 0xF0 0x90 0x90 0x80..0xFF  #L&  [80] DESERET CAPITAL LETTER LONG I.....
-      | 0xF0 0x90 0x91 0x00..0x8F  #
-      | 0xF0 0x90 0x91 0x90..0xFF  #Lo  [78] SHAVIAN LETTER PEEP..OSMANYA LE...
-      | 0xF0 0x90 0x92 0x00..0x9D  #
-      | 0xF0 0x90 0xA0 0x80..0x85  #Lo   [6] CYPRIOT SYLLABLE A..CYPRIOT SYL...
-      | 0xF0 0x90 0xA0 0x88     #Lo       CYPRIOT SYLLABLE JO
-      | 0xF0 0x90 0xA0 0x8A..0xB5  #Lo  [44] CYPRIOT SYLLABLE KA..CYPRIOT SY...
-      | 0xF0 0x90 0xA0 0xB7..0xB8  #Lo   [2] CYPRIOT SYLLABLE XA..CYPRIOT SY...
-      | 0xF0 0x90 0xA0 0xBC     #Lo       CYPRIOT SYLLABLE ZA
-      | 0xF0 0x90 0xA0 0xBF     #Lo       CYPRIOT SYLLABLE ZO
-      | 0xF0 0x90 0xA4 0x80..0x95  #Lo  [22] PHOENICIAN LETTER ALF..PHOENICI...
-      | 0xF0 0x90 0xA4 0xA0..0xB9  #Lo  [26] LYDIAN LETTER A..LYDIAN LETTER C
-      | 0xF0 0x90 0xA8 0x80     #Lo       KHAROSHTHI LETTER A
-      | 0xF0 0x90 0xA8 0x81..0x83  #Mn   [3] KHAROSHTHI VOWEL SIGN I..KHAROS...
-      | 0xF0 0x90 0xA8 0x85..0x86  #Mn   [2] KHAROSHTHI VOWEL SIGN E..KHAROS...
-      | 0xF0 0x90 0xA8 0x8C..0x8F  #Mn   [4] KHAROSHTHI VOWEL LENGTH MARK..K...
-      | 0xF0 0x90 0xA8 0x90..0x93  #Lo   [4] KHAROSHTHI LETTER KA..KHAROSHTH...
-      | 0xF0 0x90 0xA8 0x95..0x97  #Lo   [3] KHAROSHTHI LETTER CA..KHAROSHTH...
-      | 0xF0 0x90 0xA8 0x99..0xB3  #Lo  [27] KHAROSHTHI LETTER NYA..KHAROSHT...
-      | 0xF0 0x92 0x80 0x80..0xFF        #Lo [879] CUNEIFORM SIGN A..CUNEIFO...
-      | 0xF0 0x92 0x81..0x8C 0x00..0xFF  #
-      | 0xF0 0x92 0x8D 0x00..0xAE        #
-      | 0xF0 0x92 0x90 0x80..0xFF  #Nl  [99] CUNEIFORM NUMERIC SIGN TWO ASH....
-      | 0xF0 0x92 0x91 0x00..0xA2  #
-      | 0xF0 0x9D 0x90 0x80..0xFF  #L&  [85] MATHEMATICAL BOLD CAPITAL A..MA...
-      | 0xF0 0x9D 0x91 0x00..0x94  #
-      | 0xF0 0x9D 0x91 0x96..0xFF  #L&  [71] MATHEMATICAL ITALIC SMALL I..MA...
-      | 0xF0 0x9D 0x92 0x00..0x9C  #
-      | 0xF0 0x9D 0x92 0x9E..0x9F  #L&   [2] MATHEMATICAL SCRIPT CAPITAL C.....
-      | 0xF0 0x9D 0x92 0xA2     #L&       MATHEMATICAL SCRIPT CAPITAL G
-      | 0xF0 0x9D 0x92 0xA5..0xA6  #L&   [2] MATHEMATICAL SCRIPT CAPITAL J.....
-      | 0xF0 0x9D 0x92 0xA9..0xAC  #L&   [4] MATHEMATICAL SCRIPT CAPITAL N.....
-      | 0xF0 0x9D 0x92 0xAE..0xB9  #L&  [12] MATHEMATICAL SCRIPT CAPITAL S.....
-      | 0xF0 0x9D 0x92 0xBB     #L&       MATHEMATICAL SCRIPT SMALL F
-      | 0xF0 0x9D 0x92 0xBD..0xFF  #L&   [7] MATHEMATICAL SCRIPT SMALL H..MA...
-      | 0xF0 0x9D 0x93 0x00..0x83  #
-      | 0xF0 0x9D 0x93 0x85..0xFF  #L&  [65] MATHEMATICAL SCRIPT SMALL P..MA...
-      | 0xF0 0x9D 0x94 0x00..0x85  #
-      | 0xF0 0x9D 0x94 0x87..0x8A  #L&   [4] MATHEMATICAL FRAKTUR CAPITAL D....
-      | 0xF0 0x9D 0x94 0x8D..0x94  #L&   [8] MATHEMATICAL FRAKTUR CAPITAL J....
-      | 0xF0 0x9D 0x94 0x96..0x9C  #L&   [7] MATHEMATICAL FRAKTUR CAPITAL S....
-      | 0xF0 0x9D 0x94 0x9E..0xB9  #L&  [28] MATHEMATICAL FRAKTUR SMALL A..M...
-      | 0xF0 0x9D 0x94 0xBB..0xBE  #L&   [4] MATHEMATICAL DOUBLE-STRUCK CAPI...
-      | 0xF0 0x9D 0x95 0x80..0x84  #L&   [5] MATHEMATICAL DOUBLE-STRUCK CAPI...
-      | 0xF0 0x9D 0x95 0x86     #L&       MATHEMATICAL DOUBLE-STRUCK CAPITAL O
-      | 0xF0 0x9D 0x95 0x8A..0x90  #L&   [7] MATHEMATICAL DOUBLE-STRUCK CAPI...
-      | 0xF0 0x9D 0x95 0x92..0xFF        #L& [340] MATHEMATICAL DOUBLE-STRUC...
-      | 0xF0 0x9D 0x96..0x99 0x00..0xFF  #
-      | 0xF0 0x9D 0x9A 0x00..0xA5        #
-      | 0xF0 0x9D 0x9A 0xA8..0xFF  #L&  [25] MATHEMATICAL BOLD CAPITAL ALPHA...
-      | 0xF0 0x9D 0x9B 0x00..0x80  #
-      | 0xF0 0x9D 0x9B 0x82..0x9A  #L&  [25] MATHEMATICAL BOLD SMALL ALPHA.....
-      | 0xF0 0x9D 0x9B 0x9C..0xBA  #L&  [31] MATHEMATICAL BOLD EPSILON SYMBO...
-      | 0xF0 0x9D 0x9B 0xBC..0xFF  #L&  [25] MATHEMATICAL ITALIC SMALL ALPHA...
-      | 0xF0 0x9D 0x9C 0x00..0x94  #
-      | 0xF0 0x9D 0x9C 0x96..0xB4  #L&  [31] MATHEMATICAL ITALIC EPSILON SYM...
-      | 0xF0 0x9D 0x9C 0xB6..0xFF  #L&  [25] MATHEMATICAL BOLD ITALIC SMALL ...
-      | 0xF0 0x9D 0x9D 0x00..0x8E  #
-      | 0xF0 0x9D 0x9D 0x90..0xAE  #L&  [31] MATHEMATICAL BOLD ITALIC EPSILO...
-      | 0xF0 0x9D 0x9D 0xB0..0xFF  #L&  [25] MATHEMATICAL SANS-SERIF BOLD SM...
-      | 0xF0 0x9D 0x9E 0x00..0x88  #
-      | 0xF0 0x9D 0x9E 0x8A..0xA8  #L&  [31] MATHEMATICAL SANS-SERIF BOLD EP...
-      | 0xF0 0x9D 0x9E 0xAA..0xFF  #L&  [25] MATHEMATICAL SANS-SERIF BOLD IT...
-      | 0xF0 0x9D 0x9F 0x00..0x82  #
-      | 0xF0 0x9D 0x9F 0x84..0x8B  #L&   [8] MATHEMATICAL SANS-SERIF BOLD IT...
-      | 0xF0 0xA0 0x80 0x80..0xFF              #Lo [42711] CJK UNIFIED IDEOG...
-      | 0xF0 0xA0 0x81..0xFF 0x00..0xFF        #
-      | 0xF0 0xA1..0xA9 0x00..0xFF 0x00..0xFF  #
-      | 0xF0 0xAA 0x00 0x00..0xFF              #
-      | 0xF0 0xAA 0x01..0x9A 0x00..0xFF        #
-      | 0xF0 0xAA 0x9B 0x00..0x96              #
-      | 0xF0 0xAF 0xA0 0x80..0xFF        #Lo [542] CJK COMPATIBILITY IDEOGRA...
-      | 0xF0 0xAF 0xA1..0xA7 0x00..0xFF  #
-      | 0xF0 0xAF 0xA8 0x00..0x9D        #
-      ;
-
-    ulower = 
-        0x61..0x7A              #L&  [26] LATIN SMALL LETTER A..LATIN SMALL ...
-      | 0xC2 0xAA               #L&       FEMININE ORDINAL INDICATOR
-      | 0xC2 0xB5               #L&       MICRO SIGN
-      | 0xC2 0xBA               #L&       MASCULINE ORDINAL INDICATOR
-      | 0xC3 0x9F..0xB6         #L&  [24] LATIN SMALL LETTER SHARP S..LATIN ...
-      | 0xC3 0xB8..0xBF         #L&   [8] LATIN SMALL LETTER O WITH STROKE.....
-      | 0xC4 0x81               #L&       LATIN SMALL LETTER A WITH MACRON
-      | 0xC4 0x83               #L&       LATIN SMALL LETTER A WITH BREVE
-      | 0xC4 0x85               #L&       LATIN SMALL LETTER A WITH OGONEK
-      | 0xC4 0x87               #L&       LATIN SMALL LETTER C WITH ACUTE
-      | 0xC4 0x89               #L&       LATIN SMALL LETTER C WITH CIRCUMFLEX
-      | 0xC4 0x8B               #L&       LATIN SMALL LETTER C WITH DOT ABOVE
-      | 0xC4 0x8D               #L&       LATIN SMALL LETTER C WITH CARON
-      | 0xC4 0x8F               #L&       LATIN SMALL LETTER D WITH CARON
-      | 0xC4 0x91               #L&       LATIN SMALL LETTER D WITH STROKE
-      | 0xC4 0x93               #L&       LATIN SMALL LETTER E WITH MACRON
-      | 0xC4 0x95               #L&       LATIN SMALL LETTER E WITH BREVE
-      | 0xC4 0x97               #L&       LATIN SMALL LETTER E WITH DOT ABOVE
-      | 0xC4 0x99               #L&       LATIN SMALL LETTER E WITH OGONEK
-      | 0xC4 0x9B               #L&       LATIN SMALL LETTER E WITH CARON
-      | 0xC4 0x9D               #L&       LATIN SMALL LETTER G WITH CIRCUMFLEX
-      | 0xC4 0x9F               #L&       LATIN SMALL LETTER G WITH BREVE
-      | 0xC4 0xA1               #L&       LATIN SMALL LETTER G WITH DOT ABOVE
-      | 0xC4 0xA3               #L&       LATIN SMALL LETTER G WITH CEDILLA
-      | 0xC4 0xA5               #L&       LATIN SMALL LETTER H WITH CIRCUMFLEX
-      | 0xC4 0xA7               #L&       LATIN SMALL LETTER H WITH STROKE
-      | 0xC4 0xA9               #L&       LATIN SMALL LETTER I WITH TILDE
-      | 0xC4 0xAB               #L&       LATIN SMALL LETTER I WITH MACRON
-      | 0xC4 0xAD               #L&       LATIN SMALL LETTER I WITH BREVE
-      | 0xC4 0xAF               #L&       LATIN SMALL LETTER I WITH OGONEK
-      | 0xC4 0xB1               #L&       LATIN SMALL LETTER DOTLESS I
-      | 0xC4 0xB3               #L&       LATIN SMALL LIGATURE IJ
-      | 0xC4 0xB5               #L&       LATIN SMALL LETTER J WITH CIRCUMFLEX
-      | 0xC4 0xB7..0xB8         #L&   [2] LATIN SMALL LETTER K WITH CEDILLA....
-      | 0xC4 0xBA               #L&       LATIN SMALL LETTER L WITH ACUTE
-      | 0xC4 0xBC               #L&       LATIN SMALL LETTER L WITH CEDILLA
-      | 0xC4 0xBE               #L&       LATIN SMALL LETTER L WITH CARON
-      | 0xC5 0x80               #L&       LATIN SMALL LETTER L WITH MIDDLE DOT
-      | 0xC5 0x82               #L&       LATIN SMALL LETTER L WITH STROKE
-      | 0xC5 0x84               #L&       LATIN SMALL LETTER N WITH ACUTE
-      | 0xC5 0x86               #L&       LATIN SMALL LETTER N WITH CEDILLA
-      | 0xC5 0x88..0x89         #L&   [2] LATIN SMALL LETTER N WITH CARON..L...
-      | 0xC5 0x8B               #L&       LATIN SMALL LETTER ENG
-      | 0xC5 0x8D               #L&       LATIN SMALL LETTER O WITH MACRON
-      | 0xC5 0x8F               #L&       LATIN SMALL LETTER O WITH BREVE
-      | 0xC5 0x91               #L&       LATIN SMALL LETTER O WITH DOUBLE A...
-      | 0xC5 0x93               #L&       LATIN SMALL LIGATURE OE
-      | 0xC5 0x95               #L&       LATIN SMALL LETTER R WITH ACUTE
-      | 0xC5 0x97               #L&       LATIN SMALL LETTER R WITH CEDILLA
-      | 0xC5 0x99               #L&       LATIN SMALL LETTER R WITH CARON
-      | 0xC5 0x9B               #L&       LATIN SMALL LETTER S WITH ACUTE
-      | 0xC5 0x9D               #L&       LATIN SMALL LETTER S WITH CIRCUMFLEX
-      | 0xC5 0x9F               #L&       LATIN SMALL LETTER S WITH CEDILLA
-      | 0xC5 0xA1               #L&       LATIN SMALL LETTER S WITH CARON
-      | 0xC5 0xA3               #L&       LATIN SMALL LETTER T WITH CEDILLA
-      | 0xC5 0xA5               #L&       LATIN SMALL LETTER T WITH CARON
-      | 0xC5 0xA7               #L&       LATIN SMALL LETTER T WITH STROKE
-      | 0xC5 0xA9               #L&       LATIN SMALL LETTER U WITH TILDE
-      | 0xC5 0xAB               #L&       LATIN SMALL LETTER U WITH MACRON
-      | 0xC5 0xAD               #L&       LATIN SMALL LETTER U WITH BREVE
-      | 0xC5 0xAF               #L&       LATIN SMALL LETTER U WITH RING ABOVE
-      | 0xC5 0xB1               #L&       LATIN SMALL LETTER U WITH DOUBLE A...
-      | 0xC5 0xB3               #L&       LATIN SMALL LETTER U WITH OGONEK
-      | 0xC5 0xB5               #L&       LATIN SMALL LETTER W WITH CIRCUMFLEX
-      | 0xC5 0xB7               #L&       LATIN SMALL LETTER Y WITH CIRCUMFLEX
-      | 0xC5 0xBA               #L&       LATIN SMALL LETTER Z WITH ACUTE
-      | 0xC5 0xBC               #L&       LATIN SMALL LETTER Z WITH DOT ABOVE
-      | 0xC5 0xBE..0xFF         #L&   [3] LATIN SMALL LETTER Z WITH CARON..L...
-      | 0xC6 0x00..0x80         #
-      | 0xC6 0x83               #L&       LATIN SMALL LETTER B WITH TOPBAR
-      | 0xC6 0x85               #L&       LATIN SMALL LETTER TONE SIX
-      | 0xC6 0x88               #L&       LATIN SMALL LETTER C WITH HOOK
-      | 0xC6 0x8C..0x8D         #L&   [2] LATIN SMALL LETTER D WITH TOPBAR.....
-      | 0xC6 0x92               #L&       LATIN SMALL LETTER F WITH HOOK
-      | 0xC6 0x95               #L&       LATIN SMALL LETTER HV
-      | 0xC6 0x99..0x9B         #L&   [3] LATIN SMALL LETTER K WITH HOOK..LA...
-      | 0xC6 0x9E               #L&       LATIN SMALL LETTER N WITH LONG RIG...
-      | 0xC6 0xA1               #L&       LATIN SMALL LETTER O WITH HORN
-      | 0xC6 0xA3               #L&       LATIN SMALL LETTER OI
-      | 0xC6 0xA5               #L&       LATIN SMALL LETTER P WITH HOOK
-      | 0xC6 0xA8               #L&       LATIN SMALL LETTER TONE TWO
-      | 0xC6 0xAA..0xAB         #L&   [2] LATIN LETTER REVERSED ESH LOOP..LA...
-      | 0xC6 0xAD               #L&       LATIN SMALL LETTER T WITH HOOK
-      | 0xC6 0xB0               #L&       LATIN SMALL LETTER U WITH HORN
-      | 0xC6 0xB4               #L&       LATIN SMALL LETTER Y WITH HOOK
-      | 0xC6 0xB6               #L&       LATIN SMALL LETTER Z WITH STROKE
-      | 0xC6 0xB9..0xBA         #L&   [2] LATIN SMALL LETTER EZH REVERSED..L...
-      | 0xC6 0xBD..0xBF         #L&   [3] LATIN SMALL LETTER TONE FIVE..LATI...
-      | 0xC7 0x86               #L&       LATIN SMALL LETTER DZ WITH CARON
-      | 0xC7 0x89               #L&       LATIN SMALL LETTER LJ
-      | 0xC7 0x8C               #L&       LATIN SMALL LETTER NJ
-      | 0xC7 0x8E               #L&       LATIN SMALL LETTER A WITH CARON
-      | 0xC7 0x90               #L&       LATIN SMALL LETTER I WITH CARON
-      | 0xC7 0x92               #L&       LATIN SMALL LETTER O WITH CARON
-      | 0xC7 0x94               #L&       LATIN SMALL LETTER U WITH CARON
-      | 0xC7 0x96               #L&       LATIN SMALL LETTER U WITH DIAERESI...
-      | 0xC7 0x98               #L&       LATIN SMALL LETTER U WITH DIAERESI...
-      | 0xC7 0x9A               #L&       LATIN SMALL LETTER U WITH DIAERESI...
-      | 0xC7 0x9C..0x9D         #L&   [2] LATIN SMALL LETTER U WITH DIAERESI...
-      | 0xC7 0x9F               #L&       LATIN SMALL LETTER A WITH DIAERESI...
-      | 0xC7 0xA1               #L&       LATIN SMALL LETTER A WITH DOT ABOV...
-      | 0xC7 0xA3               #L&       LATIN SMALL LETTER AE WITH MACRON
-      | 0xC7 0xA5               #L&       LATIN SMALL LETTER G WITH STROKE
-      | 0xC7 0xA7               #L&       LATIN SMALL LETTER G WITH CARON
-      | 0xC7 0xA9               #L&       LATIN SMALL LETTER K WITH CARON
-      | 0xC7 0xAB               #L&       LATIN SMALL LETTER O WITH OGONEK
-      | 0xC7 0xAD               #L&       LATIN SMALL LETTER O WITH OGONEK A...
-      | 0xC7 0xAF..0xB0         #L&   [2] LATIN SMALL LETTER EZH WITH CARON....
-      | 0xC7 0xB3               #L&       LATIN SMALL LETTER DZ
-      | 0xC7 0xB5               #L&       LATIN SMALL LETTER G WITH ACUTE
-      | 0xC7 0xB9               #L&       LATIN SMALL LETTER N WITH GRAVE
-      | 0xC7 0xBB               #L&       LATIN SMALL LETTER A WITH RING ABO...
-      | 0xC7 0xBD               #L&       LATIN SMALL LETTER AE WITH ACUTE
-      | 0xC7 0xBF               #L&       LATIN SMALL LETTER O WITH STROKE A...
-      | 0xC8 0x81               #L&       LATIN SMALL LETTER A WITH DOUBLE G...
-      | 0xC8 0x83               #L&       LATIN SMALL LETTER A WITH INVERTED...
-      | 0xC8 0x85               #L&       LATIN SMALL LETTER E WITH DOUBLE G...
-      | 0xC8 0x87               #L&       LATIN SMALL LETTER E WITH INVERTED...
-      | 0xC8 0x89               #L&       LATIN SMALL LETTER I WITH DOUBLE G...
-      | 0xC8 0x8B               #L&       LATIN SMALL LETTER I WITH INVERTED...
-      | 0xC8 0x8D               #L&       LATIN SMALL LETTER O WITH DOUBLE G...
-      | 0xC8 0x8F               #L&       LATIN SMALL LETTER O WITH INVERTED...
-      | 0xC8 0x91               #L&       LATIN SMALL LETTER R WITH DOUBLE G...
-      | 0xC8 0x93               #L&       LATIN SMALL LETTER R WITH INVERTED...
-      | 0xC8 0x95               #L&       LATIN SMALL LETTER U WITH DOUBLE G...
-      | 0xC8 0x97               #L&       LATIN SMALL LETTER U WITH INVERTED...
-      | 0xC8 0x99               #L&       LATIN SMALL LETTER S WITH COMMA BELOW
-      | 0xC8 0x9B               #L&       LATIN SMALL LETTER T WITH COMMA BELOW
-      | 0xC8 0x9D               #L&       LATIN SMALL LETTER YOGH
-      | 0xC8 0x9F               #L&       LATIN SMALL LETTER H WITH CARON
-      | 0xC8 0xA1               #L&       LATIN SMALL LETTER D WITH CURL
-      | 0xC8 0xA3               #L&       LATIN SMALL LETTER OU
-      | 0xC8 0xA5               #L&       LATIN SMALL LETTER Z WITH HOOK
-      | 0xC8 0xA7               #L&       LATIN SMALL LETTER A WITH DOT ABOVE
-      | 0xC8 0xA9               #L&       LATIN SMALL LETTER E WITH CEDILLA
-      | 0xC8 0xAB               #L&       LATIN SMALL LETTER O WITH DIAERESI...
-      | 0xC8 0xAD               #L&       LATIN SMALL LETTER O WITH TILDE AN...
-      | 0xC8 0xAF               #L&       LATIN SMALL LETTER O WITH DOT ABOVE
-      | 0xC8 0xB1               #L&       LATIN SMALL LETTER O WITH DOT ABOV...
-      | 0xC8 0xB3..0xB9         #L&   [7] LATIN SMALL LETTER Y WITH MACRON.....
-      | 0xC8 0xBC               #L&       LATIN SMALL LETTER C WITH STROKE
-      | 0xC8 0xBF..0xFF         #L&   [2] LATIN SMALL LETTER S WITH SWASH TA...
-      | 0xC9 0x00..0x80         #
-      | 0xC9 0x82               #L&       LATIN SMALL LETTER GLOTTAL STOP
-      | 0xC9 0x87               #L&       LATIN SMALL LETTER E WITH STROKE
-      | 0xC9 0x89               #L&       LATIN SMALL LETTER J WITH STROKE
-      | 0xC9 0x8B               #L&       LATIN SMALL LETTER Q WITH HOOK TAIL
-      | 0xC9 0x8D               #L&       LATIN SMALL LETTER R WITH STROKE
-      | 0xC9 0x8F..0xFF         #L&  [69] LATIN SMALL LETTER Y WITH STROKE.....
-      | 0xCA 0x00..0x93         #
-      | 0xCA 0x95..0xAF         #L&  [27] LATIN LETTER PHARYNGEAL VOICED FRI...
-      | 0xCA 0xB0..0xB8         #Lm   [9] MODIFIER LETTER SMALL H..MODIFIER ...
-      | 0xCB 0x80..0x81         #Lm   [2] MODIFIER LETTER GLOTTAL STOP..MODI...
-      | 0xCB 0xA0..0xA4         #Lm   [5] MODIFIER LETTER SMALL GAMMA..MODIF...
-      | 0xCD 0x85               #Mn       COMBINING GREEK YPOGEGRAMMENI
-      | 0xCD 0xB1               #L&       GREEK SMALL LETTER HETA
-      | 0xCD 0xB3               #L&       GREEK SMALL LETTER ARCHAIC SAMPI
-      | 0xCD 0xB7               #L&       GREEK SMALL LETTER PAMPHYLIAN DIGAMMA
-      | 0xCD 0xBA               #Lm       GREEK YPOGEGRAMMENI
-      | 0xCD 0xBB..0xBD         #L&   [3] GREEK SMALL REVERSED LUNATE SIGMA ...
-      | 0xCE 0x90               #L&       GREEK SMALL LETTER IOTA WITH DIALY...
-      | 0xCE 0xAC..0xFF         #L&  [35] GREEK SMALL LETTER ALPHA WITH TONO...
-      | 0xCF 0x00..0x8E         #
-      | 0xCF 0x90..0x91         #L&   [2] GREEK BETA SYMBOL..GREEK THETA SYMBOL
-      | 0xCF 0x95..0x97         #L&   [3] GREEK PHI SYMBOL..GREEK KAI SYMBOL
-      | 0xCF 0x99               #L&       GREEK SMALL LETTER ARCHAIC KOPPA
-      | 0xCF 0x9B               #L&       GREEK SMALL LETTER STIGMA
-      | 0xCF 0x9D               #L&       GREEK SMALL LETTER DIGAMMA
-      | 0xCF 0x9F               #L&       GREEK SMALL LETTER KOPPA
-      | 0xCF 0xA1               #L&       GREEK SMALL LETTER SAMPI
-      | 0xCF 0xA3               #L&       COPTIC SMALL LETTER SHEI
-      | 0xCF 0xA5               #L&       COPTIC SMALL LETTER FEI
-      | 0xCF 0xA7               #L&       COPTIC SMALL LETTER KHEI
-      | 0xCF 0xA9               #L&       COPTIC SMALL LETTER HORI
-      | 0xCF 0xAB               #L&       COPTIC SMALL LETTER GANGIA
-      | 0xCF 0xAD               #L&       COPTIC SMALL LETTER SHIMA
-      | 0xCF 0xAF..0xB3         #L&   [5] COPTIC SMALL LETTER DEI..GREEK LET...
-      | 0xCF 0xB5               #L&       GREEK LUNATE EPSILON SYMBOL
-      | 0xCF 0xB8               #L&       GREEK SMALL LETTER SHO
-      | 0xCF 0xBB..0xBC         #L&   [2] GREEK SMALL LETTER SAN..GREEK RHO ...
-      | 0xD0 0xB0..0xFF         #L&  [48] CYRILLIC SMALL LETTER A..CYRILLIC ...
-      | 0xD1 0x00..0x9F         #
-      | 0xD1 0xA1               #L&       CYRILLIC SMALL LETTER OMEGA
-      | 0xD1 0xA3               #L&       CYRILLIC SMALL LETTER YAT
-      | 0xD1 0xA5               #L&       CYRILLIC SMALL LETTER IOTIFIED E
-      | 0xD1 0xA7               #L&       CYRILLIC SMALL LETTER LITTLE YUS
-      | 0xD1 0xA9               #L&       CYRILLIC SMALL LETTER IOTIFIED LIT...
-      | 0xD1 0xAB               #L&       CYRILLIC SMALL LETTER BIG YUS
-      | 0xD1 0xAD               #L&       CYRILLIC SMALL LETTER IOTIFIED BIG...
-      | 0xD1 0xAF               #L&       CYRILLIC SMALL LETTER KSI
-      | 0xD1 0xB1               #L&       CYRILLIC SMALL LETTER PSI
-      | 0xD1 0xB3               #L&       CYRILLIC SMALL LETTER FITA
-      | 0xD1 0xB5               #L&       CYRILLIC SMALL LETTER IZHITSA
-      | 0xD1 0xB7               #L&       CYRILLIC SMALL LETTER IZHITSA WITH...
-      | 0xD1 0xB9               #L&       CYRILLIC SMALL LETTER UK
-      | 0xD1 0xBB               #L&       CYRILLIC SMALL LETTER ROUND OMEGA
-      | 0xD1 0xBD               #L&       CYRILLIC SMALL LETTER OMEGA WITH T...
-      | 0xD1 0xBF               #L&       CYRILLIC SMALL LETTER OT
-      | 0xD2 0x81               #L&       CYRILLIC SMALL LETTER KOPPA
-      | 0xD2 0x8B               #L&       CYRILLIC SMALL LETTER SHORT I WITH...
-      | 0xD2 0x8D               #L&       CYRILLIC SMALL LETTER SEMISOFT SIGN
-      | 0xD2 0x8F               #L&       CYRILLIC SMALL LETTER ER WITH TICK
-      | 0xD2 0x91               #L&       CYRILLIC SMALL LETTER GHE WITH UPTURN
-      | 0xD2 0x93               #L&       CYRILLIC SMALL LETTER GHE WITH STROKE
-      | 0xD2 0x95               #L&       CYRILLIC SMALL LETTER GHE WITH MID...
-      | 0xD2 0x97               #L&       CYRILLIC SMALL LETTER ZHE WITH DES...
-      | 0xD2 0x99               #L&       CYRILLIC SMALL LETTER ZE WITH DESC...
-      | 0xD2 0x9B               #L&       CYRILLIC SMALL LETTER KA WITH DESC...
-      | 0xD2 0x9D               #L&       CYRILLIC SMALL LETTER KA WITH VERT...
-      | 0xD2 0x9F               #L&       CYRILLIC SMALL LETTER KA WITH STROKE
-      | 0xD2 0xA1               #L&       CYRILLIC SMALL LETTER BASHKIR KA
-      | 0xD2 0xA3               #L&       CYRILLIC SMALL LETTER EN WITH DESC...
-      | 0xD2 0xA5               #L&       CYRILLIC SMALL LIGATURE EN GHE
-      | 0xD2 0xA7               #L&       CYRILLIC SMALL LETTER PE WITH MIDD...
-      | 0xD2 0xA9               #L&       CYRILLIC SMALL LETTER ABKHASIAN HA
-      | 0xD2 0xAB               #L&       CYRILLIC SMALL LETTER ES WITH DESC...
-      | 0xD2 0xAD               #L&       CYRILLIC SMALL LETTER TE WITH DESC...
-      | 0xD2 0xAF               #L&       CYRILLIC SMALL LETTER STRAIGHT U
-      | 0xD2 0xB1               #L&       CYRILLIC SMALL LETTER STRAIGHT U W...
-      | 0xD2 0xB3               #L&       CYRILLIC SMALL LETTER HA WITH DESC...
-      | 0xD2 0xB5               #L&       CYRILLIC SMALL LIGATURE TE TSE
-      | 0xD2 0xB7               #L&       CYRILLIC SMALL LETTER CHE WITH DES...
-      | 0xD2 0xB9               #L&       CYRILLIC SMALL LETTER CHE WITH VER...
-      | 0xD2 0xBB               #L&       CYRILLIC SMALL LETTER SHHA
-      | 0xD2 0xBD               #L&       CYRILLIC SMALL LETTER ABKHASIAN CHE
-      | 0xD2 0xBF               #L&       CYRILLIC SMALL LETTER ABKHASIAN CH...
-      | 0xD3 0x82               #L&       CYRILLIC SMALL LETTER ZHE WITH BREVE
-      | 0xD3 0x84               #L&       CYRILLIC SMALL LETTER KA WITH HOOK
-      | 0xD3 0x86               #L&       CYRILLIC SMALL LETTER EL WITH TAIL
-      | 0xD3 0x88               #L&       CYRILLIC SMALL LETTER EN WITH HOOK
-      | 0xD3 0x8A               #L&       CYRILLIC SMALL LETTER EN WITH TAIL
-      | 0xD3 0x8C               #L&       CYRILLIC SMALL LETTER KHAKASSIAN CHE
-      | 0xD3 0x8E..0x8F         #L&   [2] CYRILLIC SMALL LETTER EM WITH TAIL...
-      | 0xD3 0x91               #L&       CYRILLIC SMALL LETTER A WITH BREVE
-      | 0xD3 0x93               #L&       CYRILLIC SMALL LETTER A WITH DIAER...
-      | 0xD3 0x95               #L&       CYRILLIC SMALL LIGATURE A IE
-      | 0xD3 0x97               #L&       CYRILLIC SMALL LETTER IE WITH BREVE
-      | 0xD3 0x99               #L&       CYRILLIC SMALL LETTER SCHWA
-      | 0xD3 0x9B               #L&       CYRILLIC SMALL LETTER SCHWA WITH D...
-      | 0xD3 0x9D               #L&       CYRILLIC SMALL LETTER ZHE WITH DIA...
-      | 0xD3 0x9F               #L&       CYRILLIC SMALL LETTER ZE WITH DIAE...
-      | 0xD3 0xA1               #L&       CYRILLIC SMALL LETTER ABKHASIAN DZE
-      | 0xD3 0xA3               #L&       CYRILLIC SMALL LETTER I WITH MACRON
-      | 0xD3 0xA5               #L&       CYRILLIC SMALL LETTER I WITH DIAER...
-      | 0xD3 0xA7               #L&       CYRILLIC SMALL LETTER O WITH DIAER...
-      | 0xD3 0xA9               #L&       CYRILLIC SMALL LETTER BARRED O
-      | 0xD3 0xAB               #L&       CYRILLIC SMALL LETTER BARRED O WIT...
-      | 0xD3 0xAD               #L&       CYRILLIC SMALL LETTER E WITH DIAER...
-      | 0xD3 0xAF               #L&       CYRILLIC SMALL LETTER U WITH MACRON
-      | 0xD3 0xB1               #L&       CYRILLIC SMALL LETTER U WITH DIAER...
-      | 0xD3 0xB3               #L&       CYRILLIC SMALL LETTER U WITH DOUBL...
-      | 0xD3 0xB5               #L&       CYRILLIC SMALL LETTER CHE WITH DIA...
-      | 0xD3 0xB7               #L&       CYRILLIC SMALL LETTER GHE WITH DES...
-      | 0xD3 0xB9               #L&       CYRILLIC SMALL LETTER YERU WITH DI...
-      | 0xD3 0xBB               #L&       CYRILLIC SMALL LETTER GHE WITH STR...
-      | 0xD3 0xBD               #L&       CYRILLIC SMALL LETTER HA WITH HOOK
-      | 0xD3 0xBF               #L&       CYRILLIC SMALL LETTER HA WITH STROKE
-      | 0xD4 0x81               #L&       CYRILLIC SMALL LETTER KOMI DE
-      | 0xD4 0x83               #L&       CYRILLIC SMALL LETTER KOMI DJE
-      | 0xD4 0x85               #L&       CYRILLIC SMALL LETTER KOMI ZJE
-      | 0xD4 0x87               #L&       CYRILLIC SMALL LETTER KOMI DZJE
-      | 0xD4 0x89               #L&       CYRILLIC SMALL LETTER KOMI LJE
-      | 0xD4 0x8B               #L&       CYRILLIC SMALL LETTER KOMI NJE
-      | 0xD4 0x8D               #L&       CYRILLIC SMALL LETTER KOMI SJE
-      | 0xD4 0x8F               #L&       CYRILLIC SMALL LETTER KOMI TJE
-      | 0xD4 0x91               #L&       CYRILLIC SMALL LETTER REVERSED ZE
-      | 0xD4 0x93               #L&       CYRILLIC SMALL LETTER EL WITH HOOK
-      | 0xD4 0x95               #L&       CYRILLIC SMALL LETTER LHA
-      | 0xD4 0x97               #L&       CYRILLIC SMALL LETTER RHA
-      | 0xD4 0x99               #L&       CYRILLIC SMALL LETTER YAE
-      | 0xD4 0x9B               #L&       CYRILLIC SMALL LETTER QA
-      | 0xD4 0x9D               #L&       CYRILLIC SMALL LETTER WE
-      | 0xD4 0x9F               #L&       CYRILLIC SMALL LETTER ALEUT KA
-      | 0xD4 0xA1               #L&       CYRILLIC SMALL LETTER EL WITH MIDD...
-      | 0xD4 0xA3               #L&       CYRILLIC SMALL LETTER EN WITH MIDD...
-      | 0xD5 0xA1..0xFF         #L&  [39] ARMENIAN SMALL LETTER AYB..ARMENIA...
-      | 0xD6 0x00..0x87         #
-      | 0xE1 0xB4 0x80..0xAB    #L&  [44] LATIN LETTER SMALL CAPITAL A..CYRI...
-      | 0xE1 0xB4 0xAC..0xFF    #Lm  [54] MODIFIER LETTER CAPITAL A..MODIFIE...
-      | 0xE1 0xB5 0x00..0xA1    #
-      | 0xE1 0xB5 0xA2..0xB7    #L&  [22] LATIN SUBSCRIPT SMALL LETTER I..LA...
-      | 0xE1 0xB5 0xB8          #Lm       MODIFIER LETTER CYRILLIC EN
-      | 0xE1 0xB5 0xB9..0xFF    #L&  [34] LATIN SMALL LETTER INSULAR G..LATI...
-      | 0xE1 0xB6 0x00..0x9A    #
-      | 0xE1 0xB6 0x9B..0xBF    #Lm  [37] MODIFIER LETTER SMALL TURNED ALPHA...
-      | 0xE1 0xB8 0x81          #L&       LATIN SMALL LETTER A WITH RING BELOW
-      | 0xE1 0xB8 0x83          #L&       LATIN SMALL LETTER B WITH DOT ABOVE
-      | 0xE1 0xB8 0x85          #L&       LATIN SMALL LETTER B WITH DOT BELOW
-      | 0xE1 0xB8 0x87          #L&       LATIN SMALL LETTER B WITH LINE BELOW
-      | 0xE1 0xB8 0x89          #L&       LATIN SMALL LETTER C WITH CEDILLA ...
-      | 0xE1 0xB8 0x8B          #L&       LATIN SMALL LETTER D WITH DOT ABOVE
-      | 0xE1 0xB8 0x8D          #L&       LATIN SMALL LETTER D WITH DOT BELOW
-      | 0xE1 0xB8 0x8F          #L&       LATIN SMALL LETTER D WITH LINE BELOW
-      | 0xE1 0xB8 0x91          #L&       LATIN SMALL LETTER D WITH CEDILLA
-      | 0xE1 0xB8 0x93          #L&       LATIN SMALL LETTER D WITH CIRCUMFL...
-      | 0xE1 0xB8 0x95          #L&       LATIN SMALL LETTER E WITH MACRON A...
-      | 0xE1 0xB8 0x97          #L&       LATIN SMALL LETTER E WITH MACRON A...
-      | 0xE1 0xB8 0x99          #L&       LATIN SMALL LETTER E WITH CIRCUMFL...
-      | 0xE1 0xB8 0x9B          #L&       LATIN SMALL LETTER E WITH TILDE BELOW
-      | 0xE1 0xB8 0x9D          #L&       LATIN SMALL LETTER E WITH CEDILLA ...
-      | 0xE1 0xB8 0x9F          #L&       LATIN SMALL LETTER F WITH DOT ABOVE
-      | 0xE1 0xB8 0xA1          #L&       LATIN SMALL LETTER G WITH MACRON
-      | 0xE1 0xB8 0xA3          #L&       LATIN SMALL LETTER H WITH DOT ABOVE
-      | 0xE1 0xB8 0xA5          #L&       LATIN SMALL LETTER H WITH DOT BELOW
-      | 0xE1 0xB8 0xA7          #L&       LATIN SMALL LETTER H WITH DIAERESIS
-      | 0xE1 0xB8 0xA9          #L&       LATIN SMALL LETTER H WITH CEDILLA
-      | 0xE1 0xB8 0xAB          #L&       LATIN SMALL LETTER H WITH BREVE BELOW
-      | 0xE1 0xB8 0xAD          #L&       LATIN SMALL LETTER I WITH TILDE BELOW
-      | 0xE1 0xB8 0xAF          #L&       LATIN SMALL LETTER I WITH DIAERESI...
-      | 0xE1 0xB8 0xB1          #L&       LATIN SMALL LETTER K WITH ACUTE
-      | 0xE1 0xB8 0xB3          #L&       LATIN SMALL LETTER K WITH DOT BELOW
-      | 0xE1 0xB8 0xB5          #L&       LATIN SMALL LETTER K WITH LINE BELOW
-      | 0xE1 0xB8 0xB7          #L&       LATIN SMALL LETTER L WITH DOT BELOW
-      | 0xE1 0xB8 0xB9          #L&       LATIN SMALL LETTER L WITH DOT BELO...
-      | 0xE1 0xB8 0xBB          #L&       LATIN SMALL LETTER L WITH LINE BELOW
-      | 0xE1 0xB8 0xBD          #L&       LATIN SMALL LETTER L WITH CIRCUMFL...
-      | 0xE1 0xB8 0xBF          #L&       LATIN SMALL LETTER M WITH ACUTE
-      | 0xE1 0xB9 0x81          #L&       LATIN SMALL LETTER M WITH DOT ABOVE
-      | 0xE1 0xB9 0x83          #L&       LATIN SMALL LETTER M WITH DOT BELOW
-      | 0xE1 0xB9 0x85          #L&       LATIN SMALL LETTER N WITH DOT ABOVE
-      | 0xE1 0xB9 0x87          #L&       LATIN SMALL LETTER N WITH DOT BELOW
-      | 0xE1 0xB9 0x89          #L&       LATIN SMALL LETTER N WITH LINE BELOW
-      | 0xE1 0xB9 0x8B          #L&       LATIN SMALL LETTER N WITH CIRCUMFL...
-      | 0xE1 0xB9 0x8D          #L&       LATIN SMALL LETTER O WITH TILDE AN...
-      | 0xE1 0xB9 0x8F          #L&       LATIN SMALL LETTER O WITH TILDE AN...
-      | 0xE1 0xB9 0x91          #L&       LATIN SMALL LETTER O WITH MACRON A...
-      | 0xE1 0xB9 0x93          #L&       LATIN SMALL LETTER O WITH MACRON A...
-      | 0xE1 0xB9 0x95          #L&       LATIN SMALL LETTER P WITH ACUTE
-      | 0xE1 0xB9 0x97          #L&       LATIN SMALL LETTER P WITH DOT ABOVE
-      | 0xE1 0xB9 0x99          #L&       LATIN SMALL LETTER R WITH DOT ABOVE
-      | 0xE1 0xB9 0x9B          #L&       LATIN SMALL LETTER R WITH DOT BELOW
-      | 0xE1 0xB9 0x9D          #L&       LATIN SMALL LETTER R WITH DOT BELO...
-      | 0xE1 0xB9 0x9F          #L&       LATIN SMALL LETTER R WITH LINE BELOW
-      | 0xE1 0xB9 0xA1          #L&       LATIN SMALL LETTER S WITH DOT ABOVE
-      | 0xE1 0xB9 0xA3          #L&       LATIN SMALL LETTER S WITH DOT BELOW
-      | 0xE1 0xB9 0xA5          #L&       LATIN SMALL LETTER S WITH ACUTE AN...
-      | 0xE1 0xB9 0xA7          #L&       LATIN SMALL LETTER S WITH CARON AN...
-      | 0xE1 0xB9 0xA9          #L&       LATIN SMALL LETTER S WITH DOT BELO...
-      | 0xE1 0xB9 0xAB          #L&       LATIN SMALL LETTER T WITH DOT ABOVE
-      | 0xE1 0xB9 0xAD          #L&       LATIN SMALL LETTER T WITH DOT BELOW
-      | 0xE1 0xB9 0xAF          #L&       LATIN SMALL LETTER T WITH LINE BELOW
-      | 0xE1 0xB9 0xB1          #L&       LATIN SMALL LETTER T WITH CIRCUMFL...
-      | 0xE1 0xB9 0xB3          #L&       LATIN SMALL LETTER U WITH DIAERESI...
-      | 0xE1 0xB9 0xB5          #L&       LATIN SMALL LETTER U WITH TILDE BELOW
-      | 0xE1 0xB9 0xB7          #L&       LATIN SMALL LETTER U WITH CIRCUMFL...
-      | 0xE1 0xB9 0xB9          #L&       LATIN SMALL LETTER U WITH TILDE AN...
-      | 0xE1 0xB9 0xBB          #L&       LATIN SMALL LETTER U WITH MACRON A...
-      | 0xE1 0xB9 0xBD          #L&       LATIN SMALL LETTER V WITH TILDE
-      | 0xE1 0xB9 0xBF          #L&       LATIN SMALL LETTER V WITH DOT BELOW
-      | 0xE1 0xBA 0x81          #L&       LATIN SMALL LETTER W WITH GRAVE
-      | 0xE1 0xBA 0x83          #L&       LATIN SMALL LETTER W WITH ACUTE
-      | 0xE1 0xBA 0x85          #L&       LATIN SMALL LETTER W WITH DIAERESIS
-      | 0xE1 0xBA 0x87          #L&       LATIN SMALL LETTER W WITH DOT ABOVE
-      | 0xE1 0xBA 0x89          #L&       LATIN SMALL LETTER W WITH DOT BELOW
-      | 0xE1 0xBA 0x8B          #L&       LATIN SMALL LETTER X WITH DOT ABOVE
-      | 0xE1 0xBA 0x8D          #L&       LATIN SMALL LETTER X WITH DIAERESIS
-      | 0xE1 0xBA 0x8F          #L&       LATIN SMALL LETTER Y WITH DOT ABOVE
-      | 0xE1 0xBA 0x91          #L&       LATIN SMALL LETTER Z WITH CIRCUMFLEX
-      | 0xE1 0xBA 0x93          #L&       LATIN SMALL LETTER Z WITH DOT BELOW
-      | 0xE1 0xBA 0x95..0x9D    #L&   [9] LATIN SMALL LETTER Z WITH LINE BEL...
-      | 0xE1 0xBA 0x9F          #L&       LATIN SMALL LETTER DELTA
-      | 0xE1 0xBA 0xA1          #L&       LATIN SMALL LETTER A WITH DOT BELOW
-      | 0xE1 0xBA 0xA3          #L&       LATIN SMALL LETTER A WITH HOOK ABOVE
-      | 0xE1 0xBA 0xA5          #L&       LATIN SMALL LETTER A WITH CIRCUMFL...
-      | 0xE1 0xBA 0xA7          #L&       LATIN SMALL LETTER A WITH CIRCUMFL...
-      | 0xE1 0xBA 0xA9          #L&       LATIN SMALL LETTER A WITH CIRCUMFL...
-      | 0xE1 0xBA 0xAB          #L&       LATIN SMALL LETTER A WITH CIRCUMFL...
-      | 0xE1 0xBA 0xAD          #L&       LATIN SMALL LETTER A WITH CIRCUMFL...
-      | 0xE1 0xBA 0xAF          #L&       LATIN SMALL LETTER A WITH BREVE AN...
-      | 0xE1 0xBA 0xB1          #L&       LATIN SMALL LETTER A WITH BREVE AN...
-      | 0xE1 0xBA 0xB3          #L&       LATIN SMALL LETTER A WITH BREVE AN...
-      | 0xE1 0xBA 0xB5          #L&       LATIN SMALL LETTER A WITH BREVE AN...
-      | 0xE1 0xBA 0xB7          #L&       LATIN SMALL LETTER A WITH BREVE AN...
-      | 0xE1 0xBA 0xB9          #L&       LATIN SMALL LETTER E WITH DOT BELOW
-      | 0xE1 0xBA 0xBB          #L&       LATIN SMALL LETTER E WITH HOOK ABOVE
-      | 0xE1 0xBA 0xBD          #L&       LATIN SMALL LETTER E WITH TILDE
-      | 0xE1 0xBA 0xBF          #L&       LATIN SMALL LETTER E WITH CIRCUMFL...
-      | 0xE1 0xBB 0x81          #L&       LATIN SMALL LETTER E WITH CIRCUMFL...
-      | 0xE1 0xBB 0x83          #L&       LATIN SMALL LETTER E WITH CIRCUMFL...
-      | 0xE1 0xBB 0x85          #L&       LATIN SMALL LETTER E WITH CIRCUMFL...
-      | 0xE1 0xBB 0x87          #L&       LATIN SMALL LETTER E WITH CIRCUMFL...
-      | 0xE1 0xBB 0x89          #L&       LATIN SMALL LETTER I WITH HOOK ABOVE
-      | 0xE1 0xBB 0x8B          #L&       LATIN SMALL LETTER I WITH DOT BELOW
-      | 0xE1 0xBB 0x8D          #L&       LATIN SMALL LETTER O WITH DOT BELOW
-      | 0xE1 0xBB 0x8F          #L&       LATIN SMALL LETTER O WITH HOOK ABOVE
-      | 0xE1 0xBB 0x91          #L&       LATIN SMALL LETTER O WITH CIRCUMFL...
-      | 0xE1 0xBB 0x93          #L&       LATIN SMALL LETTER O WITH CIRCUMFL...
-      | 0xE1 0xBB 0x95          #L&       LATIN SMALL LETTER O WITH CIRCUMFL...
-      | 0xE1 0xBB 0x97          #L&       LATIN SMALL LETTER O WITH CIRCUMFL...
-      | 0xE1 0xBB 0x99          #L&       LATIN SMALL LETTER O WITH CIRCUMFL...
-      | 0xE1 0xBB 0x9B          #L&       LATIN SMALL LETTER O WITH HORN AND...
-      | 0xE1 0xBB 0x9D          #L&       LATIN SMALL LETTER O WITH HORN AND...
-      | 0xE1 0xBB 0x9F          #L&       LATIN SMALL LETTER O WITH HORN AND...
-      | 0xE1 0xBB 0xA1          #L&       LATIN SMALL LETTER O WITH HORN AND...
-      | 0xE1 0xBB 0xA3          #L&       LATIN SMALL LETTER O WITH HORN AND...
-      | 0xE1 0xBB 0xA5          #L&       LATIN SMALL LETTER U WITH DOT BELOW
-      | 0xE1 0xBB 0xA7          #L&       LATIN SMALL LETTER U WITH HOOK ABOVE
-      | 0xE1 0xBB 0xA9          #L&       LATIN SMALL LETTER U WITH HORN AND...
-      | 0xE1 0xBB 0xAB          #L&       LATIN SMALL LETTER U WITH HORN AND...
-      | 0xE1 0xBB 0xAD          #L&       LATIN SMALL LETTER U WITH HORN AND...
-      | 0xE1 0xBB 0xAF          #L&       LATIN SMALL LETTER U WITH HORN AND...
-      | 0xE1 0xBB 0xB1          #L&       LATIN SMALL LETTER U WITH HORN AND...
-      | 0xE1 0xBB 0xB3          #L&       LATIN SMALL LETTER Y WITH GRAVE
-      | 0xE1 0xBB 0xB5          #L&       LATIN SMALL LETTER Y WITH DOT BELOW
-      | 0xE1 0xBB 0xB7          #L&       LATIN SMALL LETTER Y WITH HOOK ABOVE
-      | 0xE1 0xBB 0xB9          #L&       LATIN SMALL LETTER Y WITH TILDE
-      | 0xE1 0xBB 0xBB          #L&       LATIN SMALL LETTER MIDDLE-WELSH LL
-      | 0xE1 0xBB 0xBD          #L&       LATIN SMALL LETTER MIDDLE-WELSH V
-      | 0xE1 0xBB 0xBF..0xFF    #L&   [9] LATIN SMALL LETTER Y WITH LOOP..GR...
-      | 0xE1 0xBC 0x00..0x87    #
-      | 0xE1 0xBC 0x90..0x95    #L&   [6] GREEK SMALL LETTER EPSILON WITH PS...
-      | 0xE1 0xBC 0xA0..0xA7    #L&   [8] GREEK SMALL LETTER ETA WITH PSILI....
-      | 0xE1 0xBC 0xB0..0xB7    #L&   [8] GREEK SMALL LETTER IOTA WITH PSILI...
-      | 0xE1 0xBD 0x80..0x85    #L&   [6] GREEK SMALL LETTER OMICRON WITH PS...
-      | 0xE1 0xBD 0x90..0x97    #L&   [8] GREEK SMALL LETTER UPSILON WITH PS...
-      | 0xE1 0xBD 0xA0..0xA7    #L&   [8] GREEK SMALL LETTER OMEGA WITH PSIL...
-      | 0xE1 0xBD 0xB0..0xBD    #L&  [14] GREEK SMALL LETTER ALPHA WITH VARI...
-      | 0xE1 0xBE 0x80..0x87    #L&   [8] GREEK SMALL LETTER ALPHA WITH PSIL...
-      | 0xE1 0xBE 0x90..0x97    #L&   [8] GREEK SMALL LETTER ETA WITH PSILI ...
-      | 0xE1 0xBE 0xA0..0xA7    #L&   [8] GREEK SMALL LETTER OMEGA WITH PSIL...
-      | 0xE1 0xBE 0xB0..0xB4    #L&   [5] GREEK SMALL LETTER ALPHA WITH VRAC...
-      | 0xE1 0xBE 0xB6..0xB7    #L&   [2] GREEK SMALL LETTER ALPHA WITH PERI...
-      | 0xE1 0xBE 0xBE          #L&       GREEK PROSGEGRAMMENI
-      | 0xE1 0xBF 0x82..0x84    #L&   [3] GREEK SMALL LETTER ETA WITH VARIA ...
-      | 0xE1 0xBF 0x86..0x87    #L&   [2] GREEK SMALL LETTER ETA WITH PERISP...
-      | 0xE1 0xBF 0x90..0x93    #L&   [4] GREEK SMALL LETTER IOTA WITH VRACH...
-      | 0xE1 0xBF 0x96..0x97    #L&   [2] GREEK SMALL LETTER IOTA WITH PERIS...
-      | 0xE1 0xBF 0xA0..0xA7    #L&   [8] GREEK SMALL LETTER UPSILON WITH VR...
-      | 0xE1 0xBF 0xB2..0xB4    #L&   [3] GREEK SMALL LETTER OMEGA WITH VARI...
-      | 0xE1 0xBF 0xB6..0xB7    #L&   [2] GREEK SMALL LETTER OMEGA WITH PERI...
-      | 0xE2 0x81 0xB1          #L&       SUPERSCRIPT LATIN SMALL LETTER I
-      | 0xE2 0x81 0xBF          #L&       SUPERSCRIPT LATIN SMALL LETTER N
-      | 0xE2 0x82 0x90..0x94    #Lm   [5] LATIN SUBSCRIPT SMALL LETTER A..LA...
-      | 0xE2 0x84 0x8A          #L&       SCRIPT SMALL G
-      | 0xE2 0x84 0x8E..0x8F    #L&   [2] PLANCK CONSTANT..PLANCK CONSTANT O...
-      | 0xE2 0x84 0x93          #L&       SCRIPT SMALL L
-      | 0xE2 0x84 0xAF          #L&       SCRIPT SMALL E
-      | 0xE2 0x84 0xB4          #L&       SCRIPT SMALL O
-      | 0xE2 0x84 0xB9          #L&       INFORMATION SOURCE
-      | 0xE2 0x84 0xBC..0xBD    #L&   [2] DOUBLE-STRUCK SMALL PI..DOUBLE-STR...
-      | 0xE2 0x85 0x86..0x89    #L&   [4] DOUBLE-STRUCK ITALIC SMALL D..DOUB...
-      | 0xE2 0x85 0x8E          #L&       TURNED SMALL F
-      | 0xE2 0x85 0xB0..0xBF    #Nl  [16] SMALL ROMAN NUMERAL ONE..SMALL ROM...
-      | 0xE2 0x86 0x84          #L&       LATIN SMALL LETTER REVERSED C
-      | 0xE2 0x93 0x90..0xA9    #So  [26] CIRCLED LATIN SMALL LETTER A..CIRC...
-      | 0xE2 0xB0 0xB0..0xFF    #L&  [47] GLAGOLITIC SMALL LETTER AZU..GLAGO...
-      | 0xE2 0xB1 0x00..0x9E    #
-      | 0xE2 0xB1 0xA1          #L&       LATIN SMALL LETTER L WITH DOUBLE BAR
-      | 0xE2 0xB1 0xA5..0xA6    #L&   [2] LATIN SMALL LETTER A WITH STROKE.....
-      | 0xE2 0xB1 0xA8          #L&       LATIN SMALL LETTER H WITH DESCENDER
-      | 0xE2 0xB1 0xAA          #L&       LATIN SMALL LETTER K WITH DESCENDER
-      | 0xE2 0xB1 0xAC          #L&       LATIN SMALL LETTER Z WITH DESCENDER
-      | 0xE2 0xB1 0xB1          #L&       LATIN SMALL LETTER V WITH RIGHT HOOK
-      | 0xE2 0xB1 0xB3..0xB4    #L&   [2] LATIN SMALL LETTER W WITH HOOK..LA...
-      | 0xE2 0xB1 0xB6..0xBC    #L&   [7] LATIN SMALL LETTER HALF H..LATIN S...
-      | 0xE2 0xB1 0xBD          #Lm       MODIFIER LETTER CAPITAL V
-      | 0xE2 0xB2 0x81          #L&       COPTIC SMALL LETTER ALFA
-      | 0xE2 0xB2 0x83          #L&       COPTIC SMALL LETTER VIDA
-      | 0xE2 0xB2 0x85          #L&       COPTIC SMALL LETTER GAMMA
-      | 0xE2 0xB2 0x87          #L&       COPTIC SMALL LETTER DALDA
-      | 0xE2 0xB2 0x89          #L&       COPTIC SMALL LETTER EIE
-      | 0xE2 0xB2 0x8B          #L&       COPTIC SMALL LETTER SOU
-      | 0xE2 0xB2 0x8D          #L&       COPTIC SMALL LETTER ZATA
-      | 0xE2 0xB2 0x8F          #L&       COPTIC SMALL LETTER HATE
-      | 0xE2 0xB2 0x91          #L&       COPTIC SMALL LETTER THETHE
-      | 0xE2 0xB2 0x93          #L&       COPTIC SMALL LETTER IAUDA
-      | 0xE2 0xB2 0x95          #L&       COPTIC SMALL LETTER KAPA
-      | 0xE2 0xB2 0x97          #L&       COPTIC SMALL LETTER LAULA
-      | 0xE2 0xB2 0x99          #L&       COPTIC SMALL LETTER MI
-      | 0xE2 0xB2 0x9B          #L&       COPTIC SMALL LETTER NI
-      | 0xE2 0xB2 0x9D          #L&       COPTIC SMALL LETTER KSI
-      | 0xE2 0xB2 0x9F          #L&       COPTIC SMALL LETTER O
-      | 0xE2 0xB2 0xA1          #L&       COPTIC SMALL LETTER PI
-      | 0xE2 0xB2 0xA3          #L&       COPTIC SMALL LETTER RO
-      | 0xE2 0xB2 0xA5          #L&       COPTIC SMALL LETTER SIMA
-      | 0xE2 0xB2 0xA7          #L&       COPTIC SMALL LETTER TAU
-      | 0xE2 0xB2 0xA9          #L&       COPTIC SMALL LETTER UA
-      | 0xE2 0xB2 0xAB          #L&       COPTIC SMALL LETTER FI
-      | 0xE2 0xB2 0xAD          #L&       COPTIC SMALL LETTER KHI
-      | 0xE2 0xB2 0xAF          #L&       COPTIC SMALL LETTER PSI
-      | 0xE2 0xB2 0xB1          #L&       COPTIC SMALL LETTER OOU
-      | 0xE2 0xB2 0xB3          #L&       COPTIC SMALL LETTER DIALECT-P ALEF
-      | 0xE2 0xB2 0xB5          #L&       COPTIC SMALL LETTER OLD COPTIC AIN
-      | 0xE2 0xB2 0xB7          #L&       COPTIC SMALL LETTER CRYPTOGRAMMIC EIE
-      | 0xE2 0xB2 0xB9          #L&       COPTIC SMALL LETTER DIALECT-P KAPA
-      | 0xE2 0xB2 0xBB          #L&       COPTIC SMALL LETTER DIALECT-P NI
-      | 0xE2 0xB2 0xBD          #L&       COPTIC SMALL LETTER CRYPTOGRAMMIC NI
-      | 0xE2 0xB2 0xBF          #L&       COPTIC SMALL LETTER OLD COPTIC OOU
-      | 0xE2 0xB3 0x81          #L&       COPTIC SMALL LETTER SAMPI
-      | 0xE2 0xB3 0x83          #L&       COPTIC SMALL LETTER CROSSED SHEI
-      | 0xE2 0xB3 0x85          #L&       COPTIC SMALL LETTER OLD COPTIC SHEI
-      | 0xE2 0xB3 0x87          #L&       COPTIC SMALL LETTER OLD COPTIC ESH
-      | 0xE2 0xB3 0x89          #L&       COPTIC SMALL LETTER AKHMIMIC KHEI
-      | 0xE2 0xB3 0x8B          #L&       COPTIC SMALL LETTER DIALECT-P HORI
-      | 0xE2 0xB3 0x8D          #L&       COPTIC SMALL LETTER OLD COPTIC HORI
-      | 0xE2 0xB3 0x8F          #L&       COPTIC SMALL LETTER OLD COPTIC HA
-      | 0xE2 0xB3 0x91          #L&       COPTIC SMALL LETTER L-SHAPED HA
-      | 0xE2 0xB3 0x93          #L&       COPTIC SMALL LETTER OLD COPTIC HEI
-      | 0xE2 0xB3 0x95          #L&       COPTIC SMALL LETTER OLD COPTIC HAT
-      | 0xE2 0xB3 0x97          #L&       COPTIC SMALL LETTER OLD COPTIC GANGIA
-      | 0xE2 0xB3 0x99          #L&       COPTIC SMALL LETTER OLD COPTIC DJA
-      | 0xE2 0xB3 0x9B          #L&       COPTIC SMALL LETTER OLD COPTIC SHIMA
-      | 0xE2 0xB3 0x9D          #L&       COPTIC SMALL LETTER OLD NUBIAN SHIMA
-      | 0xE2 0xB3 0x9F          #L&       COPTIC SMALL LETTER OLD NUBIAN NGI
-      | 0xE2 0xB3 0xA1          #L&       COPTIC SMALL LETTER OLD NUBIAN NYI
-      | 0xE2 0xB3 0xA3..0xA4    #L&   [2] COPTIC SMALL LETTER OLD NUBIAN WAU...
-      | 0xE2 0xB4 0x80..0xA5    #L&  [38] GEORGIAN SMALL LETTER AN..GEORGIAN...
-      | 0xEA 0x99 0x81          #L&       CYRILLIC SMALL LETTER ZEMLYA
-      | 0xEA 0x99 0x83          #L&       CYRILLIC SMALL LETTER DZELO
-      | 0xEA 0x99 0x85          #L&       CYRILLIC SMALL LETTER REVERSED DZE
-      | 0xEA 0x99 0x87          #L&       CYRILLIC SMALL LETTER IOTA
-      | 0xEA 0x99 0x89          #L&       CYRILLIC SMALL LETTER DJERV
-      | 0xEA 0x99 0x8B          #L&       CYRILLIC SMALL LETTER MONOGRAPH UK
-      | 0xEA 0x99 0x8D          #L&       CYRILLIC SMALL LETTER BROAD OMEGA
-      | 0xEA 0x99 0x8F          #L&       CYRILLIC SMALL LETTER NEUTRAL YER
-      | 0xEA 0x99 0x91          #L&       CYRILLIC SMALL LETTER YERU WITH BA...
-      | 0xEA 0x99 0x93          #L&       CYRILLIC SMALL LETTER IOTIFIED YAT
-      | 0xEA 0x99 0x95          #L&       CYRILLIC SMALL LETTER REVERSED YU
-      | 0xEA 0x99 0x97          #L&       CYRILLIC SMALL LETTER IOTIFIED A
-      | 0xEA 0x99 0x99          #L&       CYRILLIC SMALL LETTER CLOSED LITTL...
-      | 0xEA 0x99 0x9B          #L&       CYRILLIC SMALL LETTER BLENDED YUS
-      | 0xEA 0x99 0x9D          #L&       CYRILLIC SMALL LETTER IOTIFIED CLO...
-      | 0xEA 0x99 0x9F          #L&       CYRILLIC SMALL LETTER YN
-      | 0xEA 0x99 0xA3          #L&       CYRILLIC SMALL LETTER SOFT DE
-      | 0xEA 0x99 0xA5          #L&       CYRILLIC SMALL LETTER SOFT EL
-      | 0xEA 0x99 0xA7          #L&       CYRILLIC SMALL LETTER SOFT EM
-      | 0xEA 0x99 0xA9          #L&       CYRILLIC SMALL LETTER MONOCULAR O
-      | 0xEA 0x99 0xAB          #L&       CYRILLIC SMALL LETTER BINOCULAR O
-      | 0xEA 0x99 0xAD          #L&       CYRILLIC SMALL LETTER DOUBLE MONOC...
-      | 0xEA 0x9A 0x81          #L&       CYRILLIC SMALL LETTER DWE
-      | 0xEA 0x9A 0x83          #L&       CYRILLIC SMALL LETTER DZWE
-      | 0xEA 0x9A 0x85          #L&       CYRILLIC SMALL LETTER ZHWE
-      | 0xEA 0x9A 0x87          #L&       CYRILLIC SMALL LETTER CCHE
-      | 0xEA 0x9A 0x89          #L&       CYRILLIC SMALL LETTER DZZE
-      | 0xEA 0x9A 0x8B          #L&       CYRILLIC SMALL LETTER TE WITH MIDD...
-      | 0xEA 0x9A 0x8D          #L&       CYRILLIC SMALL LETTER TWE
-      | 0xEA 0x9A 0x8F          #L&       CYRILLIC SMALL LETTER TSWE
-      | 0xEA 0x9A 0x91          #L&       CYRILLIC SMALL LETTER TSSE
-      | 0xEA 0x9A 0x93          #L&       CYRILLIC SMALL LETTER TCHE
-      | 0xEA 0x9A 0x95          #L&       CYRILLIC SMALL LETTER HWE
-      | 0xEA 0x9A 0x97          #L&       CYRILLIC SMALL LETTER SHWE
-      | 0xEA 0x9C 0xA3          #L&       LATIN SMALL LETTER EGYPTOLOGICAL ALEF
-      | 0xEA 0x9C 0xA5          #L&       LATIN SMALL LETTER EGYPTOLOGICAL AIN
-      | 0xEA 0x9C 0xA7          #L&       LATIN SMALL LETTER HENG
-      | 0xEA 0x9C 0xA9          #L&       LATIN SMALL LETTER TZ
-      | 0xEA 0x9C 0xAB          #L&       LATIN SMALL LETTER TRESILLO
-      | 0xEA 0x9C 0xAD          #L&       LATIN SMALL LETTER CUATRILLO
-      | 0xEA 0x9C 0xAF..0xB1    #L&   [3] LATIN SMALL LETTER CUATRILLO WITH ...
-      | 0xEA 0x9C 0xB3          #L&       LATIN SMALL LETTER AA
-      | 0xEA 0x9C 0xB5          #L&       LATIN SMALL LETTER AO
-      | 0xEA 0x9C 0xB7          #L&       LATIN SMALL LETTER AU
-      | 0xEA 0x9C 0xB9          #L&       LATIN SMALL LETTER AV
-      | 0xEA 0x9C 0xBB          #L&       LATIN SMALL LETTER AV WITH HORIZON...
-      | 0xEA 0x9C 0xBD          #L&       LATIN SMALL LETTER AY
-      | 0xEA 0x9C 0xBF          #L&       LATIN SMALL LETTER REVERSED C WITH...
-      | 0xEA 0x9D 0x81          #L&       LATIN SMALL LETTER K WITH STROKE
-      | 0xEA 0x9D 0x83          #L&       LATIN SMALL LETTER K WITH DIAGONAL...
-      | 0xEA 0x9D 0x85          #L&       LATIN SMALL LETTER K WITH STROKE A...
-      | 0xEA 0x9D 0x87          #L&       LATIN SMALL LETTER BROKEN L
-      | 0xEA 0x9D 0x89          #L&       LATIN SMALL LETTER L WITH HIGH STROKE
-      | 0xEA 0x9D 0x8B          #L&       LATIN SMALL LETTER O WITH LONG STR...
-      | 0xEA 0x9D 0x8D          #L&       LATIN SMALL LETTER O WITH LOOP
-      | 0xEA 0x9D 0x8F          #L&       LATIN SMALL LETTER OO
-      | 0xEA 0x9D 0x91          #L&       LATIN SMALL LETTER P WITH STROKE T...
-      | 0xEA 0x9D 0x93          #L&       LATIN SMALL LETTER P WITH FLOURISH
-      | 0xEA 0x9D 0x95          #L&       LATIN SMALL LETTER P WITH SQUIRREL...
-      | 0xEA 0x9D 0x97          #L&       LATIN SMALL LETTER Q WITH STROKE T...
-      | 0xEA 0x9D 0x99          #L&       LATIN SMALL LETTER Q WITH DIAGONAL...
-      | 0xEA 0x9D 0x9B          #L&       LATIN SMALL LETTER R ROTUNDA
-      | 0xEA 0x9D 0x9D          #L&       LATIN SMALL LETTER RUM ROTUNDA
-      | 0xEA 0x9D 0x9F          #L&       LATIN SMALL LETTER V WITH DIAGONAL...
-      | 0xEA 0x9D 0xA1          #L&       LATIN SMALL LETTER VY
-      | 0xEA 0x9D 0xA3          #L&       LATIN SMALL LETTER VISIGOTHIC Z
-      | 0xEA 0x9D 0xA5          #L&       LATIN SMALL LETTER THORN WITH STROKE
-      | 0xEA 0x9D 0xA7          #L&       LATIN SMALL LETTER THORN WITH STRO...
-      | 0xEA 0x9D 0xA9          #L&       LATIN SMALL LETTER VEND
-      | 0xEA 0x9D 0xAB          #L&       LATIN SMALL LETTER ET
-      | 0xEA 0x9D 0xAD          #L&       LATIN SMALL LETTER IS
-      | 0xEA 0x9D 0xAF          #L&       LATIN SMALL LETTER CON
-      | 0xEA 0x9D 0xB0          #Lm       MODIFIER LETTER US
-      | 0xEA 0x9D 0xB1..0xB8    #L&   [8] LATIN SMALL LETTER DUM..LATIN SMAL...
-      | 0xEA 0x9D 0xBA          #L&       LATIN SMALL LETTER INSULAR D
-      | 0xEA 0x9D 0xBC          #L&       LATIN SMALL LETTER INSULAR F
-      | 0xEA 0x9D 0xBF          #L&       LATIN SMALL LETTER TURNED INSULAR G
-      | 0xEA 0x9E 0x81          #L&       LATIN SMALL LETTER TURNED L
-      | 0xEA 0x9E 0x83          #L&       LATIN SMALL LETTER INSULAR R
-      | 0xEA 0x9E 0x85          #L&       LATIN SMALL LETTER INSULAR S
-      | 0xEA 0x9E 0x87          #L&       LATIN SMALL LETTER INSULAR T
-      | 0xEA 0x9E 0x8C          #L&       LATIN SMALL LETTER SALTILLO
-      | 0xEF 0xAC 0x80..0x86    #L&   [7] LATIN SMALL LIGATURE FF..LATIN SMA...
-      | 0xEF 0xAC 0x93..0x97    #L&   [5] ARMENIAN SMALL LIGATURE MEN NOW..A...
-      | 0xEF 0xBD 0x81..0x9A    #L&  [26] FULLWIDTH LATIN SMALL LETTER A..FU...
-      | 0xF0 0x90 0x90 0xA8..0xFF  #L&  [40] DESERET SMALL LETTER LONG I..DE...
-      | 0xF0 0x90 0x91 0x00..0x8F  #
-      | 0xF0 0x9D 0x90 0x9A..0xB3  #L&  [26] MATHEMATICAL BOLD SMALL A..MATH...
-      | 0xF0 0x9D 0x91 0x8E..0x94  #L&   [7] MATHEMATICAL ITALIC SMALL A..MA...
-      | 0xF0 0x9D 0x91 0x96..0xA7  #L&  [18] MATHEMATICAL ITALIC SMALL I..MA...
-      | 0xF0 0x9D 0x92 0x82..0x9B  #L&  [26] MATHEMATICAL BOLD ITALIC SMALL ...
-      | 0xF0 0x9D 0x92 0xB6..0xB9  #L&   [4] MATHEMATICAL SCRIPT SMALL A..MA...
-      | 0xF0 0x9D 0x92 0xBB     #L&       MATHEMATICAL SCRIPT SMALL F
-      | 0xF0 0x9D 0x92 0xBD..0xFF  #L&   [7] MATHEMATICAL SCRIPT SMALL H..MA...
-      | 0xF0 0x9D 0x93 0x00..0x83  #
-      | 0xF0 0x9D 0x93 0x85..0x8F  #L&  [11] MATHEMATICAL SCRIPT SMALL P..MA...
-      | 0xF0 0x9D 0x93 0xAA..0xFF  #L&  [26] MATHEMATICAL BOLD SCRIPT SMALL ...
-      | 0xF0 0x9D 0x94 0x00..0x83  #
-      | 0xF0 0x9D 0x94 0x9E..0xB7  #L&  [26] MATHEMATICAL FRAKTUR SMALL A..M...
-      | 0xF0 0x9D 0x95 0x92..0xAB  #L&  [26] MATHEMATICAL DOUBLE-STRUCK SMAL...
-      | 0xF0 0x9D 0x96 0x86..0x9F  #L&  [26] MATHEMATICAL BOLD FRAKTUR SMALL...
-      | 0xF0 0x9D 0x96 0xBA..0xFF  #L&  [26] MATHEMATICAL SANS-SERIF SMALL A...
-      | 0xF0 0x9D 0x97 0x00..0x93  #
-      | 0xF0 0x9D 0x97 0xAE..0xFF  #L&  [26] MATHEMATICAL SANS-SERIF BOLD SM...
-      | 0xF0 0x9D 0x98 0x00..0x87  #
-      | 0xF0 0x9D 0x98 0xA2..0xBB  #L&  [26] MATHEMATICAL SANS-SERIF ITALIC ...
-      | 0xF0 0x9D 0x99 0x96..0xAF  #L&  [26] MATHEMATICAL SANS-SERIF BOLD IT...
-      | 0xF0 0x9D 0x9A 0x8A..0xA5  #L&  [28] MATHEMATICAL MONOSPACE SMALL A....
-      | 0xF0 0x9D 0x9B 0x82..0x9A  #L&  [25] MATHEMATICAL BOLD SMALL ALPHA.....
-      | 0xF0 0x9D 0x9B 0x9C..0xA1  #L&   [6] MATHEMATICAL BOLD EPSILON SYMBO...
-      | 0xF0 0x9D 0x9B 0xBC..0xFF  #L&  [25] MATHEMATICAL ITALIC SMALL ALPHA...
-      | 0xF0 0x9D 0x9C 0x00..0x94  #
-      | 0xF0 0x9D 0x9C 0x96..0x9B  #L&   [6] MATHEMATICAL ITALIC EPSILON SYM...
-      | 0xF0 0x9D 0x9C 0xB6..0xFF  #L&  [25] MATHEMATICAL BOLD ITALIC SMALL ...
-      | 0xF0 0x9D 0x9D 0x00..0x8E  #
-      | 0xF0 0x9D 0x9D 0x90..0x95  #L&   [6] MATHEMATICAL BOLD ITALIC EPSILO...
-      | 0xF0 0x9D 0x9D 0xB0..0xFF  #L&  [25] MATHEMATICAL SANS-SERIF BOLD SM...
-      | 0xF0 0x9D 0x9E 0x00..0x88  #
-      | 0xF0 0x9D 0x9E 0x8A..0x8F  #L&   [6] MATHEMATICAL SANS-SERIF BOLD EP...
-      | 0xF0 0x9D 0x9E 0xAA..0xFF  #L&  [25] MATHEMATICAL SANS-SERIF BOLD IT...
-      | 0xF0 0x9D 0x9F 0x00..0x82  #
-      | 0xF0 0x9D 0x9F 0x84..0x89  #L&   [6] MATHEMATICAL SANS-SERIF BOLD IT...
-      | 0xF0 0x9D 0x9F 0x8B     #L&       MATHEMATICAL BOLD SMALL DIGAMMA
-      ;
-
-    uupper = 
-        0x41..0x5A              #L&  [26] LATIN CAPITAL LETTER A..LATIN CAPI...
-      | 0xC3 0x80..0x96         #L&  [23] LATIN CAPITAL LETTER A WITH GRAVE....
-      | 0xC3 0x98..0x9E         #L&   [7] LATIN CAPITAL LETTER O WITH STROKE...
-      | 0xC4 0x80               #L&       LATIN CAPITAL LETTER A WITH MACRON
-      | 0xC4 0x82               #L&       LATIN CAPITAL LETTER A WITH BREVE
-      | 0xC4 0x84               #L&       LATIN CAPITAL LETTER A WITH OGONEK
-      | 0xC4 0x86               #L&       LATIN CAPITAL LETTER C WITH ACUTE
-      | 0xC4 0x88               #L&       LATIN CAPITAL LETTER C WITH CIRCUM...
-      | 0xC4 0x8A               #L&       LATIN CAPITAL LETTER C WITH DOT ABOVE
-      | 0xC4 0x8C               #L&       LATIN CAPITAL LETTER C WITH CARON
-      | 0xC4 0x8E               #L&       LATIN CAPITAL LETTER D WITH CARON
-      | 0xC4 0x90               #L&       LATIN CAPITAL LETTER D WITH STROKE
-      | 0xC4 0x92               #L&       LATIN CAPITAL LETTER E WITH MACRON
-      | 0xC4 0x94               #L&       LATIN CAPITAL LETTER E WITH BREVE
-      | 0xC4 0x96               #L&       LATIN CAPITAL LETTER E WITH DOT ABOVE
-      | 0xC4 0x98               #L&       LATIN CAPITAL LETTER E WITH OGONEK
-      | 0xC4 0x9A               #L&       LATIN CAPITAL LETTER E WITH CARON
-      | 0xC4 0x9C               #L&       LATIN CAPITAL LETTER G WITH CIRCUM...
-      | 0xC4 0x9E               #L&       LATIN CAPITAL LETTER G WITH BREVE
-      | 0xC4 0xA0               #L&       LATIN CAPITAL LETTER G WITH DOT ABOVE
-      | 0xC4 0xA2               #L&       LATIN CAPITAL LETTER G WITH CEDILLA
-      | 0xC4 0xA4               #L&       LATIN CAPITAL LETTER H WITH CIRCUM...
-      | 0xC4 0xA6               #L&       LATIN CAPITAL LETTER H WITH STROKE
-      | 0xC4 0xA8               #L&       LATIN CAPITAL LETTER I WITH TILDE
-      | 0xC4 0xAA               #L&       LATIN CAPITAL LETTER I WITH MACRON
-      | 0xC4 0xAC               #L&       LATIN CAPITAL LETTER I WITH BREVE
-      | 0xC4 0xAE               #L&       LATIN CAPITAL LETTER I WITH OGONEK
-      | 0xC4 0xB0               #L&       LATIN CAPITAL LETTER I WITH DOT ABOVE
-      | 0xC4 0xB2               #L&       LATIN CAPITAL LIGATURE IJ
-      | 0xC4 0xB4               #L&       LATIN CAPITAL LETTER J WITH CIRCUM...
-      | 0xC4 0xB6               #L&       LATIN CAPITAL LETTER K WITH CEDILLA
-      | 0xC4 0xB9               #L&       LATIN CAPITAL LETTER L WITH ACUTE
-      | 0xC4 0xBB               #L&       LATIN CAPITAL LETTER L WITH CEDILLA
-      | 0xC4 0xBD               #L&       LATIN CAPITAL LETTER L WITH CARON
-      | 0xC4 0xBF               #L&       LATIN CAPITAL LETTER L WITH MIDDLE...
-      | 0xC5 0x81               #L&       LATIN CAPITAL LETTER L WITH STROKE
-      | 0xC5 0x83               #L&       LATIN CAPITAL LETTER N WITH ACUTE
-      | 0xC5 0x85               #L&       LATIN CAPITAL LETTER N WITH CEDILLA
-      | 0xC5 0x87               #L&       LATIN CAPITAL LETTER N WITH CARON
-      | 0xC5 0x8A               #L&       LATIN CAPITAL LETTER ENG
-      | 0xC5 0x8C               #L&       LATIN CAPITAL LETTER O WITH MACRON
-      | 0xC5 0x8E               #L&       LATIN CAPITAL LETTER O WITH BREVE
-      | 0xC5 0x90               #L&       LATIN CAPITAL LETTER O WITH DOUBLE...
-      | 0xC5 0x92               #L&       LATIN CAPITAL LIGATURE OE
-      | 0xC5 0x94               #L&       LATIN CAPITAL LETTER R WITH ACUTE
-      | 0xC5 0x96               #L&       LATIN CAPITAL LETTER R WITH CEDILLA
-      | 0xC5 0x98               #L&       LATIN CAPITAL LETTER R WITH CARON
-      | 0xC5 0x9A               #L&       LATIN CAPITAL LETTER S WITH ACUTE
-      | 0xC5 0x9C               #L&       LATIN CAPITAL LETTER S WITH CIRCUM...
-      | 0xC5 0x9E               #L&       LATIN CAPITAL LETTER S WITH CEDILLA
-      | 0xC5 0xA0               #L&       LATIN CAPITAL LETTER S WITH CARON
-      | 0xC5 0xA2               #L&       LATIN CAPITAL LETTER T WITH CEDILLA
-      | 0xC5 0xA4               #L&       LATIN CAPITAL LETTER T WITH CARON
-      | 0xC5 0xA6               #L&       LATIN CAPITAL LETTER T WITH STROKE
-      | 0xC5 0xA8               #L&       LATIN CAPITAL LETTER U WITH TILDE
-      | 0xC5 0xAA               #L&       LATIN CAPITAL LETTER U WITH MACRON
-      | 0xC5 0xAC               #L&       LATIN CAPITAL LETTER U WITH BREVE
-      | 0xC5 0xAE               #L&       LATIN CAPITAL LETTER U WITH RING A...
-      | 0xC5 0xB0               #L&       LATIN CAPITAL LETTER U WITH DOUBLE...
-      | 0xC5 0xB2               #L&       LATIN CAPITAL LETTER U WITH OGONEK
-      | 0xC5 0xB4               #L&       LATIN CAPITAL LETTER W WITH CIRCUM...
-      | 0xC5 0xB6               #L&       LATIN CAPITAL LETTER Y WITH CIRCUM...
-      | 0xC5 0xB8..0xB9         #L&   [2] LATIN CAPITAL LETTER Y WITH DIAERE...
-      | 0xC5 0xBB               #L&       LATIN CAPITAL LETTER Z WITH DOT ABOVE
-      | 0xC5 0xBD               #L&       LATIN CAPITAL LETTER Z WITH CARON
-      | 0xC6 0x81..0x82         #L&   [2] LATIN CAPITAL LETTER B WITH HOOK.....
-      | 0xC6 0x84               #L&       LATIN CAPITAL LETTER TONE SIX
-      | 0xC6 0x86..0x87         #L&   [2] LATIN CAPITAL LETTER OPEN O..LATIN...
-      | 0xC6 0x89..0x8B         #L&   [3] LATIN CAPITAL LETTER AFRICAN D..LA...
-      | 0xC6 0x8E..0x91         #L&   [4] LATIN CAPITAL LETTER REVERSED E..L...
-      | 0xC6 0x93..0x94         #L&   [2] LATIN CAPITAL LETTER G WITH HOOK.....
-      | 0xC6 0x96..0x98         #L&   [3] LATIN CAPITAL LETTER IOTA..LATIN C...
-      | 0xC6 0x9C..0x9D         #L&   [2] LATIN CAPITAL LETTER TURNED M..LAT...
-      | 0xC6 0x9F..0xA0         #L&   [2] LATIN CAPITAL LETTER O WITH MIDDLE...
-      | 0xC6 0xA2               #L&       LATIN CAPITAL LETTER OI
-      | 0xC6 0xA4               #L&       LATIN CAPITAL LETTER P WITH HOOK
-      | 0xC6 0xA6..0xA7         #L&   [2] LATIN LETTER YR..LATIN CAPITAL LET...
-      | 0xC6 0xA9               #L&       LATIN CAPITAL LETTER ESH
-      | 0xC6 0xAC               #L&       LATIN CAPITAL LETTER T WITH HOOK
-      | 0xC6 0xAE..0xAF         #L&   [2] LATIN CAPITAL LETTER T WITH RETROF...
-      | 0xC6 0xB1..0xB3         #L&   [3] LATIN CAPITAL LETTER UPSILON..LATI...
-      | 0xC6 0xB5               #L&       LATIN CAPITAL LETTER Z WITH STROKE
-      | 0xC6 0xB7..0xB8         #L&   [2] LATIN CAPITAL LETTER EZH..LATIN CA...
-      | 0xC6 0xBC               #L&       LATIN CAPITAL LETTER TONE FIVE
-      | 0xC7 0x84               #L&       LATIN CAPITAL LETTER DZ WITH CARON
-      | 0xC7 0x87               #L&       LATIN CAPITAL LETTER LJ
-      | 0xC7 0x8A               #L&       LATIN CAPITAL LETTER NJ
-      | 0xC7 0x8D               #L&       LATIN CAPITAL LETTER A WITH CARON
-      | 0xC7 0x8F               #L&       LATIN CAPITAL LETTER I WITH CARON
-      | 0xC7 0x91               #L&       LATIN CAPITAL LETTER O WITH CARON
-      | 0xC7 0x93               #L&       LATIN CAPITAL LETTER U WITH CARON
-      | 0xC7 0x95               #L&       LATIN CAPITAL LETTER U WITH DIAERE...
-      | 0xC7 0x97               #L&       LATIN CAPITAL LETTER U WITH DIAERE...
-      | 0xC7 0x99               #L&       LATIN CAPITAL LETTER U WITH DIAERE...
-      | 0xC7 0x9B               #L&       LATIN CAPITAL LETTER U WITH DIAERE...
-      | 0xC7 0x9E               #L&       LATIN CAPITAL LETTER A WITH DIAERE...
-      | 0xC7 0xA0               #L&       LATIN CAPITAL LETTER A WITH DOT AB...
-      | 0xC7 0xA2               #L&       LATIN CAPITAL LETTER AE WITH MACRON
-      | 0xC7 0xA4               #L&       LATIN CAPITAL LETTER G WITH STROKE
-      | 0xC7 0xA6               #L&       LATIN CAPITAL LETTER G WITH CARON
-      | 0xC7 0xA8               #L&       LATIN CAPITAL LETTER K WITH CARON
-      | 0xC7 0xAA               #L&       LATIN CAPITAL LETTER O WITH OGONEK
-      | 0xC7 0xAC               #L&       LATIN CAPITAL LETTER O WITH OGONEK...
-      | 0xC7 0xAE               #L&       LATIN CAPITAL LETTER EZH WITH CARON
-      | 0xC7 0xB1               #L&       LATIN CAPITAL LETTER DZ
-      | 0xC7 0xB4               #L&       LATIN CAPITAL LETTER G WITH ACUTE
-      | 0xC7 0xB6..0xB8         #L&   [3] LATIN CAPITAL LETTER HWAIR..LATIN ...
-      | 0xC7 0xBA               #L&       LATIN CAPITAL LETTER A WITH RING A...
-      | 0xC7 0xBC               #L&       LATIN CAPITAL LETTER AE WITH ACUTE
-      | 0xC7 0xBE               #L&       LATIN CAPITAL LETTER O WITH STROKE...
-      | 0xC8 0x80               #L&       LATIN CAPITAL LETTER A WITH DOUBLE...
-      | 0xC8 0x82               #L&       LATIN CAPITAL LETTER A WITH INVERT...
-      | 0xC8 0x84               #L&       LATIN CAPITAL LETTER E WITH DOUBLE...
-      | 0xC8 0x86               #L&       LATIN CAPITAL LETTER E WITH INVERT...
-      | 0xC8 0x88               #L&       LATIN CAPITAL LETTER I WITH DOUBLE...
-      | 0xC8 0x8A               #L&       LATIN CAPITAL LETTER I WITH INVERT...
-      | 0xC8 0x8C               #L&       LATIN CAPITAL LETTER O WITH DOUBLE...
-      | 0xC8 0x8E               #L&       LATIN CAPITAL LETTER O WITH INVERT...
-      | 0xC8 0x90               #L&       LATIN CAPITAL LETTER R WITH DOUBLE...
-      | 0xC8 0x92               #L&       LATIN CAPITAL LETTER R WITH INVERT...
-      | 0xC8 0x94               #L&       LATIN CAPITAL LETTER U WITH DOUBLE...
-      | 0xC8 0x96               #L&       LATIN CAPITAL LETTER U WITH INVERT...
-      | 0xC8 0x98               #L&       LATIN CAPITAL LETTER S WITH COMMA ...
-      | 0xC8 0x9A               #L&       LATIN CAPITAL LETTER T WITH COMMA ...
-      | 0xC8 0x9C               #L&       LATIN CAPITAL LETTER YOGH
-      | 0xC8 0x9E               #L&       LATIN CAPITAL LETTER H WITH CARON
-      | 0xC8 0xA0               #L&       LATIN CAPITAL LETTER N WITH LONG R...
-      | 0xC8 0xA2               #L&       LATIN CAPITAL LETTER OU
-      | 0xC8 0xA4               #L&       LATIN CAPITAL LETTER Z WITH HOOK
-      | 0xC8 0xA6               #L&       LATIN CAPITAL LETTER A WITH DOT ABOVE
-      | 0xC8 0xA8               #L&       LATIN CAPITAL LETTER E WITH CEDILLA
-      | 0xC8 0xAA               #L&       LATIN CAPITAL LETTER O WITH DIAERE...
-      | 0xC8 0xAC               #L&       LATIN CAPITAL LETTER O WITH TILDE ...
-      | 0xC8 0xAE               #L&       LATIN CAPITAL LETTER O WITH DOT ABOVE
-      | 0xC8 0xB0               #L&       LATIN CAPITAL LETTER O WITH DOT AB...
-      | 0xC8 0xB2               #L&       LATIN CAPITAL LETTER Y WITH MACRON
-      | 0xC8 0xBA..0xBB         #L&   [2] LATIN CAPITAL LETTER A WITH STROKE...
-      | 0xC8 0xBD..0xBE         #L&   [2] LATIN CAPITAL LETTER L WITH BAR..L...
-      | 0xC9 0x81               #L&       LATIN CAPITAL LETTER GLOTTAL STOP
-      | 0xC9 0x83..0x86         #L&   [4] LATIN CAPITAL LETTER B WITH STROKE...
-      | 0xC9 0x88               #L&       LATIN CAPITAL LETTER J WITH STROKE
-      | 0xC9 0x8A               #L&       LATIN CAPITAL LETTER SMALL Q WITH ...
-      | 0xC9 0x8C               #L&       LATIN CAPITAL LETTER R WITH STROKE
-      | 0xC9 0x8E               #L&       LATIN CAPITAL LETTER Y WITH STROKE
-      | 0xCD 0xB0               #L&       GREEK CAPITAL LETTER HETA
-      | 0xCD 0xB2               #L&       GREEK CAPITAL LETTER ARCHAIC SAMPI
-      | 0xCD 0xB6               #L&       GREEK CAPITAL LETTER PAMPHYLIAN DI...
-      | 0xCE 0x86               #L&       GREEK CAPITAL LETTER ALPHA WITH TONOS
-      | 0xCE 0x88..0x8A         #L&   [3] GREEK CAPITAL LETTER EPSILON WITH ...
-      | 0xCE 0x8C               #L&       GREEK CAPITAL LETTER OMICRON WITH ...
-      | 0xCE 0x8E..0x8F         #L&   [2] GREEK CAPITAL LETTER UPSILON WITH ...
-      | 0xCE 0x91..0xA1         #L&  [17] GREEK CAPITAL LETTER ALPHA..GREEK ...
-      | 0xCE 0xA3..0xAB         #L&   [9] GREEK CAPITAL LETTER SIGMA..GREEK ...
-      | 0xCF 0x8F               #L&       GREEK CAPITAL KAI SYMBOL
-      | 0xCF 0x92..0x94         #L&   [3] GREEK UPSILON WITH HOOK SYMBOL..GR...
-      | 0xCF 0x98               #L&       GREEK LETTER ARCHAIC KOPPA
-      | 0xCF 0x9A               #L&       GREEK LETTER STIGMA
-      | 0xCF 0x9C               #L&       GREEK LETTER DIGAMMA
-      | 0xCF 0x9E               #L&       GREEK LETTER KOPPA
-      | 0xCF 0xA0               #L&       GREEK LETTER SAMPI
-      | 0xCF 0xA2               #L&       COPTIC CAPITAL LETTER SHEI
-      | 0xCF 0xA4               #L&       COPTIC CAPITAL LETTER FEI
-      | 0xCF 0xA6               #L&       COPTIC CAPITAL LETTER KHEI
-      | 0xCF 0xA8               #L&       COPTIC CAPITAL LETTER HORI
-      | 0xCF 0xAA               #L&       COPTIC CAPITAL LETTER GANGIA
-      | 0xCF 0xAC               #L&       COPTIC CAPITAL LETTER SHIMA
-      | 0xCF 0xAE               #L&       COPTIC CAPITAL LETTER DEI
-      | 0xCF 0xB4               #L&       GREEK CAPITAL THETA SYMBOL
-      | 0xCF 0xB7               #L&       GREEK CAPITAL LETTER SHO
-      | 0xCF 0xB9..0xBA         #L&   [2] GREEK CAPITAL LUNATE SIGMA SYMBOL....
-      | 0xCF 0xBD..0xFF         #L&  [51] GREEK CAPITAL REVERSED LUNATE SIGM...
-      | 0xD0 0x00..0xAF         #
-      | 0xD1 0xA0               #L&       CYRILLIC CAPITAL LETTER OMEGA
-      | 0xD1 0xA2               #L&       CYRILLIC CAPITAL LETTER YAT
-      | 0xD1 0xA4               #L&       CYRILLIC CAPITAL LETTER IOTIFIED E
-      | 0xD1 0xA6               #L&       CYRILLIC CAPITAL LETTER LITTLE YUS
-      | 0xD1 0xA8               #L&       CYRILLIC CAPITAL LETTER IOTIFIED L...
-      | 0xD1 0xAA               #L&       CYRILLIC CAPITAL LETTER BIG YUS
-      | 0xD1 0xAC               #L&       CYRILLIC CAPITAL LETTER IOTIFIED B...
-      | 0xD1 0xAE               #L&       CYRILLIC CAPITAL LETTER KSI
-      | 0xD1 0xB0               #L&       CYRILLIC CAPITAL LETTER PSI
-      | 0xD1 0xB2               #L&       CYRILLIC CAPITAL LETTER FITA
-      | 0xD1 0xB4               #L&       CYRILLIC CAPITAL LETTER IZHITSA
-      | 0xD1 0xB6               #L&       CYRILLIC CAPITAL LETTER IZHITSA WI...
-      | 0xD1 0xB8               #L&       CYRILLIC CAPITAL LETTER UK
-      | 0xD1 0xBA               #L&       CYRILLIC CAPITAL LETTER ROUND OMEGA
-      | 0xD1 0xBC               #L&       CYRILLIC CAPITAL LETTER OMEGA WITH...
-      | 0xD1 0xBE               #L&       CYRILLIC CAPITAL LETTER OT
-      | 0xD2 0x80               #L&       CYRILLIC CAPITAL LETTER KOPPA
-      | 0xD2 0x8A               #L&       CYRILLIC CAPITAL LETTER SHORT I WI...
-      | 0xD2 0x8C               #L&       CYRILLIC CAPITAL LETTER SEMISOFT SIGN
-      | 0xD2 0x8E               #L&       CYRILLIC CAPITAL LETTER ER WITH TICK
-      | 0xD2 0x90               #L&       CYRILLIC CAPITAL LETTER GHE WITH U...
-      | 0xD2 0x92               #L&       CYRILLIC CAPITAL LETTER GHE WITH S...
-      | 0xD2 0x94               #L&       CYRILLIC CAPITAL LETTER GHE WITH M...
-      | 0xD2 0x96               #L&       CYRILLIC CAPITAL LETTER ZHE WITH D...
-      | 0xD2 0x98               #L&       CYRILLIC CAPITAL LETTER ZE WITH DE...
-      | 0xD2 0x9A               #L&       CYRILLIC CAPITAL LETTER KA WITH DE...
-      | 0xD2 0x9C               #L&       CYRILLIC CAPITAL LETTER KA WITH VE...
-      | 0xD2 0x9E               #L&       CYRILLIC CAPITAL LETTER KA WITH ST...
-      | 0xD2 0xA0               #L&       CYRILLIC CAPITAL LETTER BASHKIR KA
-      | 0xD2 0xA2               #L&       CYRILLIC CAPITAL LETTER EN WITH DE...
-      | 0xD2 0xA4               #L&       CYRILLIC CAPITAL LIGATURE EN GHE
-      | 0xD2 0xA6               #L&       CYRILLIC CAPITAL LETTER PE WITH MI...
-      | 0xD2 0xA8               #L&       CYRILLIC CAPITAL LETTER ABKHASIAN HA
-      | 0xD2 0xAA               #L&       CYRILLIC CAPITAL LETTER ES WITH DE...
-      | 0xD2 0xAC               #L&       CYRILLIC CAPITAL LETTER TE WITH DE...
-      | 0xD2 0xAE               #L&       CYRILLIC CAPITAL LETTER STRAIGHT U
-      | 0xD2 0xB0               #L&       CYRILLIC CAPITAL LETTER STRAIGHT U...
-      | 0xD2 0xB2               #L&       CYRILLIC CAPITAL LETTER HA WITH DE...
-      | 0xD2 0xB4               #L&       CYRILLIC CAPITAL LIGATURE TE TSE
-      | 0xD2 0xB6               #L&       CYRILLIC CAPITAL LETTER CHE WITH D...
-      | 0xD2 0xB8               #L&       CYRILLIC CAPITAL LETTER CHE WITH V...
-      | 0xD2 0xBA               #L&       CYRILLIC CAPITAL LETTER SHHA
-      | 0xD2 0xBC               #L&       CYRILLIC CAPITAL LETTER ABKHASIAN CHE
-      | 0xD2 0xBE               #L&       CYRILLIC CAPITAL LETTER ABKHASIAN ...
-      | 0xD3 0x80..0x81         #L&   [2] CYRILLIC LETTER PALOCHKA..CYRILLIC...
-      | 0xD3 0x83               #L&       CYRILLIC CAPITAL LETTER KA WITH HOOK
-      | 0xD3 0x85               #L&       CYRILLIC CAPITAL LETTER EL WITH TAIL
-      | 0xD3 0x87               #L&       CYRILLIC CAPITAL LETTER EN WITH HOOK
-      | 0xD3 0x89               #L&       CYRILLIC CAPITAL LETTER EN WITH TAIL
-      | 0xD3 0x8B               #L&       CYRILLIC CAPITAL LETTER KHAKASSIAN...
-      | 0xD3 0x8D               #L&       CYRILLIC CAPITAL LETTER EM WITH TAIL
-      | 0xD3 0x90               #L&       CYRILLIC CAPITAL LETTER A WITH BREVE
-      | 0xD3 0x92               #L&       CYRILLIC CAPITAL LETTER A WITH DIA...
-      | 0xD3 0x94               #L&       CYRILLIC CAPITAL LIGATURE A IE
-      | 0xD3 0x96               #L&       CYRILLIC CAPITAL LETTER IE WITH BREVE
-      | 0xD3 0x98               #L&       CYRILLIC CAPITAL LETTER SCHWA
-      | 0xD3 0x9A               #L&       CYRILLIC CAPITAL LETTER SCHWA WITH...
-      | 0xD3 0x9C               #L&       CYRILLIC CAPITAL LETTER ZHE WITH D...
-      | 0xD3 0x9E               #L&       CYRILLIC CAPITAL LETTER ZE WITH DI...
-      | 0xD3 0xA0               #L&       CYRILLIC CAPITAL LETTER ABKHASIAN DZE
-      | 0xD3 0xA2               #L&       CYRILLIC CAPITAL LETTER I WITH MACRON
-      | 0xD3 0xA4               #L&       CYRILLIC CAPITAL LETTER I WITH DIA...
-      | 0xD3 0xA6               #L&       CYRILLIC CAPITAL LETTER O WITH DIA...
-      | 0xD3 0xA8               #L&       CYRILLIC CAPITAL LETTER BARRED O
-      | 0xD3 0xAA               #L&       CYRILLIC CAPITAL LETTER BARRED O W...
-      | 0xD3 0xAC               #L&       CYRILLIC CAPITAL LETTER E WITH DIA...
-      | 0xD3 0xAE               #L&       CYRILLIC CAPITAL LETTER U WITH MACRON
-      | 0xD3 0xB0               #L&       CYRILLIC CAPITAL LETTER U WITH DIA...
-      | 0xD3 0xB2               #L&       CYRILLIC CAPITAL LETTER U WITH DOU...
-      | 0xD3 0xB4               #L&       CYRILLIC CAPITAL LETTER CHE WITH D...
-      | 0xD3 0xB6               #L&       CYRILLIC CAPITAL LETTER GHE WITH D...
-      | 0xD3 0xB8               #L&       CYRILLIC CAPITAL LETTER YERU WITH ...
-      | 0xD3 0xBA               #L&       CYRILLIC CAPITAL LETTER GHE WITH S...
-      | 0xD3 0xBC               #L&       CYRILLIC CAPITAL LETTER HA WITH HOOK
-      | 0xD3 0xBE               #L&       CYRILLIC CAPITAL LETTER HA WITH ST...
-      | 0xD4 0x80               #L&       CYRILLIC CAPITAL LETTER KOMI DE
-      | 0xD4 0x82               #L&       CYRILLIC CAPITAL LETTER KOMI DJE
-      | 0xD4 0x84               #L&       CYRILLIC CAPITAL LETTER KOMI ZJE
-      | 0xD4 0x86               #L&       CYRILLIC CAPITAL LETTER KOMI DZJE
-      | 0xD4 0x88               #L&       CYRILLIC CAPITAL LETTER KOMI LJE
-      | 0xD4 0x8A               #L&       CYRILLIC CAPITAL LETTER KOMI NJE
-      | 0xD4 0x8C               #L&       CYRILLIC CAPITAL LETTER KOMI SJE
-      | 0xD4 0x8E               #L&       CYRILLIC CAPITAL LETTER KOMI TJE
-      | 0xD4 0x90               #L&       CYRILLIC CAPITAL LETTER REVERSED ZE
-      | 0xD4 0x92               #L&       CYRILLIC CAPITAL LETTER EL WITH HOOK
-      | 0xD4 0x94               #L&       CYRILLIC CAPITAL LETTER LHA
-      | 0xD4 0x96               #L&       CYRILLIC CAPITAL LETTER RHA
-      | 0xD4 0x98               #L&       CYRILLIC CAPITAL LETTER YAE
-      | 0xD4 0x9A               #L&       CYRILLIC CAPITAL LETTER QA
-      | 0xD4 0x9C               #L&       CYRILLIC CAPITAL LETTER WE
-      | 0xD4 0x9E               #L&       CYRILLIC CAPITAL LETTER ALEUT KA
-      | 0xD4 0xA0               #L&       CYRILLIC CAPITAL LETTER EL WITH MI...
-      | 0xD4 0xA2               #L&       CYRILLIC CAPITAL LETTER EN WITH MI...
-      | 0xD4 0xB1..0xFF         #L&  [38] ARMENIAN CAPITAL LETTER AYB..ARMEN...
-      | 0xD5 0x00..0x96         #
-      | 0xE1 0x82 0xA0..0xFF    #L&  [38] GEORGIAN CAPITAL LETTER AN..GEORGI...
-      | 0xE1 0x83 0x00..0x85    #
-      | 0xE1 0xB8 0x80          #L&       LATIN CAPITAL LETTER A WITH RING B...
-      | 0xE1 0xB8 0x82          #L&       LATIN CAPITAL LETTER B WITH DOT ABOVE
-      | 0xE1 0xB8 0x84          #L&       LATIN CAPITAL LETTER B WITH DOT BELOW
-      | 0xE1 0xB8 0x86          #L&       LATIN CAPITAL LETTER B WITH LINE B...
-      | 0xE1 0xB8 0x88          #L&       LATIN CAPITAL LETTER C WITH CEDILL...
-      | 0xE1 0xB8 0x8A          #L&       LATIN CAPITAL LETTER D WITH DOT ABOVE
-      | 0xE1 0xB8 0x8C          #L&       LATIN CAPITAL LETTER D WITH DOT BELOW
-      | 0xE1 0xB8 0x8E          #L&       LATIN CAPITAL LETTER D WITH LINE B...
-      | 0xE1 0xB8 0x90          #L&       LATIN CAPITAL LETTER D WITH CEDILLA
-      | 0xE1 0xB8 0x92          #L&       LATIN CAPITAL LETTER D WITH CIRCUM...
-      | 0xE1 0xB8 0x94          #L&       LATIN CAPITAL LETTER E WITH MACRON...
-      | 0xE1 0xB8 0x96          #L&       LATIN CAPITAL LETTER E WITH MACRON...
-      | 0xE1 0xB8 0x98          #L&       LATIN CAPITAL LETTER E WITH CIRCUM...
-      | 0xE1 0xB8 0x9A          #L&       LATIN CAPITAL LETTER E WITH TILDE ...
-      | 0xE1 0xB8 0x9C          #L&       LATIN CAPITAL LETTER E WITH CEDILL...
-      | 0xE1 0xB8 0x9E          #L&       LATIN CAPITAL LETTER F WITH DOT ABOVE
-      | 0xE1 0xB8 0xA0          #L&       LATIN CAPITAL LETTER G WITH MACRON
-      | 0xE1 0xB8 0xA2          #L&       LATIN CAPITAL LETTER H WITH DOT ABOVE
-      | 0xE1 0xB8 0xA4          #L&       LATIN CAPITAL LETTER H WITH DOT BELOW
-      | 0xE1 0xB8 0xA6          #L&       LATIN CAPITAL LETTER H WITH DIAERESIS
-      | 0xE1 0xB8 0xA8          #L&       LATIN CAPITAL LETTER H WITH CEDILLA
-      | 0xE1 0xB8 0xAA          #L&       LATIN CAPITAL LETTER H WITH BREVE ...
-      | 0xE1 0xB8 0xAC          #L&       LATIN CAPITAL LETTER I WITH TILDE ...
-      | 0xE1 0xB8 0xAE          #L&       LATIN CAPITAL LETTER I WITH DIAERE...
-      | 0xE1 0xB8 0xB0          #L&       LATIN CAPITAL LETTER K WITH ACUTE
-      | 0xE1 0xB8 0xB2          #L&       LATIN CAPITAL LETTER K WITH DOT BELOW
-      | 0xE1 0xB8 0xB4          #L&       LATIN CAPITAL LETTER K WITH LINE B...
-      | 0xE1 0xB8 0xB6          #L&       LATIN CAPITAL LETTER L WITH DOT BELOW
-      | 0xE1 0xB8 0xB8          #L&       LATIN CAPITAL LETTER L WITH DOT BE...
-      | 0xE1 0xB8 0xBA          #L&       LATIN CAPITAL LETTER L WITH LINE B...
-      | 0xE1 0xB8 0xBC          #L&       LATIN CAPITAL LETTER L WITH CIRCUM...
-      | 0xE1 0xB8 0xBE          #L&       LATIN CAPITAL LETTER M WITH ACUTE
-      | 0xE1 0xB9 0x80          #L&       LATIN CAPITAL LETTER M WITH DOT ABOVE
-      | 0xE1 0xB9 0x82          #L&       LATIN CAPITAL LETTER M WITH DOT BELOW
-      | 0xE1 0xB9 0x84          #L&       LATIN CAPITAL LETTER N WITH DOT ABOVE
-      | 0xE1 0xB9 0x86          #L&       LATIN CAPITAL LETTER N WITH DOT BELOW
-      | 0xE1 0xB9 0x88          #L&       LATIN CAPITAL LETTER N WITH LINE B...
-      | 0xE1 0xB9 0x8A          #L&       LATIN CAPITAL LETTER N WITH CIRCUM...
-      | 0xE1 0xB9 0x8C          #L&       LATIN CAPITAL LETTER O WITH TILDE ...
-      | 0xE1 0xB9 0x8E          #L&       LATIN CAPITAL LETTER O WITH TILDE ...
-      | 0xE1 0xB9 0x90          #L&       LATIN CAPITAL LETTER O WITH MACRON...
-      | 0xE1 0xB9 0x92          #L&       LATIN CAPITAL LETTER O WITH MACRON...
-      | 0xE1 0xB9 0x94          #L&       LATIN CAPITAL LETTER P WITH ACUTE
-      | 0xE1 0xB9 0x96          #L&       LATIN CAPITAL LETTER P WITH DOT ABOVE
-      | 0xE1 0xB9 0x98          #L&       LATIN CAPITAL LETTER R WITH DOT ABOVE
-      | 0xE1 0xB9 0x9A          #L&       LATIN CAPITAL LETTER R WITH DOT BELOW
-      | 0xE1 0xB9 0x9C          #L&       LATIN CAPITAL LETTER R WITH DOT BE...
-      | 0xE1 0xB9 0x9E          #L&       LATIN CAPITAL LETTER R WITH LINE B...
-      | 0xE1 0xB9 0xA0          #L&       LATIN CAPITAL LETTER S WITH DOT ABOVE
-      | 0xE1 0xB9 0xA2          #L&       LATIN CAPITAL LETTER S WITH DOT BELOW
-      | 0xE1 0xB9 0xA4          #L&       LATIN CAPITAL LETTER S WITH ACUTE ...
-      | 0xE1 0xB9 0xA6          #L&       LATIN CAPITAL LETTER S WITH CARON ...
-      | 0xE1 0xB9 0xA8          #L&       LATIN CAPITAL LETTER S WITH DOT BE...
-      | 0xE1 0xB9 0xAA          #L&       LATIN CAPITAL LETTER T WITH DOT ABOVE
-      | 0xE1 0xB9 0xAC          #L&       LATIN CAPITAL LETTER T WITH DOT BELOW
-      | 0xE1 0xB9 0xAE          #L&       LATIN CAPITAL LETTER T WITH LINE B...
-      | 0xE1 0xB9 0xB0          #L&       LATIN CAPITAL LETTER T WITH CIRCUM...
-      | 0xE1 0xB9 0xB2          #L&       LATIN CAPITAL LETTER U WITH DIAERE...
-      | 0xE1 0xB9 0xB4          #L&       LATIN CAPITAL LETTER U WITH TILDE ...
-      | 0xE1 0xB9 0xB6          #L&       LATIN CAPITAL LETTER U WITH CIRCUM...
-      | 0xE1 0xB9 0xB8          #L&       LATIN CAPITAL LETTER U WITH TILDE ...
-      | 0xE1 0xB9 0xBA          #L&       LATIN CAPITAL LETTER U WITH MACRON...
-      | 0xE1 0xB9 0xBC          #L&       LATIN CAPITAL LETTER V WITH TILDE
-      | 0xE1 0xB9 0xBE          #L&       LATIN CAPITAL LETTER V WITH DOT BELOW
-      | 0xE1 0xBA 0x80          #L&       LATIN CAPITAL LETTER W WITH GRAVE
-      | 0xE1 0xBA 0x82          #L&       LATIN CAPITAL LETTER W WITH ACUTE
-      | 0xE1 0xBA 0x84          #L&       LATIN CAPITAL LETTER W WITH DIAERESIS
-      | 0xE1 0xBA 0x86          #L&       LATIN CAPITAL LETTER W WITH DOT ABOVE
-      | 0xE1 0xBA 0x88          #L&       LATIN CAPITAL LETTER W WITH DOT BELOW
-      | 0xE1 0xBA 0x8A          #L&       LATIN CAPITAL LETTER X WITH DOT ABOVE
-      | 0xE1 0xBA 0x8C          #L&       LATIN CAPITAL LETTER X WITH DIAERESIS
-      | 0xE1 0xBA 0x8E          #L&       LATIN CAPITAL LETTER Y WITH DOT ABOVE
-      | 0xE1 0xBA 0x90          #L&       LATIN CAPITAL LETTER Z WITH CIRCUM...
-      | 0xE1 0xBA 0x92          #L&       LATIN CAPITAL LETTER Z WITH DOT BELOW
-      | 0xE1 0xBA 0x94          #L&       LATIN CAPITAL LETTER Z WITH LINE B...
-      | 0xE1 0xBA 0x9E          #L&       LATIN CAPITAL LETTER SHARP S
-      | 0xE1 0xBA 0xA0          #L&       LATIN CAPITAL LETTER A WITH DOT BELOW
-      | 0xE1 0xBA 0xA2          #L&       LATIN CAPITAL LETTER A WITH HOOK A...
-      | 0xE1 0xBA 0xA4          #L&       LATIN CAPITAL LETTER A WITH CIRCUM...
-      | 0xE1 0xBA 0xA6          #L&       LATIN CAPITAL LETTER A WITH CIRCUM...
-      | 0xE1 0xBA 0xA8          #L&       LATIN CAPITAL LETTER A WITH CIRCUM...
-      | 0xE1 0xBA 0xAA          #L&       LATIN CAPITAL LETTER A WITH CIRCUM...
-      | 0xE1 0xBA 0xAC          #L&       LATIN CAPITAL LETTER A WITH CIRCUM...
-      | 0xE1 0xBA 0xAE          #L&       LATIN CAPITAL LETTER A WITH BREVE ...
-      | 0xE1 0xBA 0xB0          #L&       LATIN CAPITAL LETTER A WITH BREVE ...
-      | 0xE1 0xBA 0xB2          #L&       LATIN CAPITAL LETTER A WITH BREVE ...
-      | 0xE1 0xBA 0xB4          #L&       LATIN CAPITAL LETTER A WITH BREVE ...
-      | 0xE1 0xBA 0xB6          #L&       LATIN CAPITAL LETTER A WITH BREVE ...
-      | 0xE1 0xBA 0xB8          #L&       LATIN CAPITAL LETTER E WITH DOT BELOW
-      | 0xE1 0xBA 0xBA          #L&       LATIN CAPITAL LETTER E WITH HOOK A...
-      | 0xE1 0xBA 0xBC          #L&       LATIN CAPITAL LETTER E WITH TILDE
-      | 0xE1 0xBA 0xBE          #L&       LATIN CAPITAL LETTER E WITH CIRCUM...
-      | 0xE1 0xBB 0x80          #L&       LATIN CAPITAL LETTER E WITH CIRCUM...
-      | 0xE1 0xBB 0x82          #L&       LATIN CAPITAL LETTER E WITH CIRCUM...
-      | 0xE1 0xBB 0x84          #L&       LATIN CAPITAL LETTER E WITH CIRCUM...
-      | 0xE1 0xBB 0x86          #L&       LATIN CAPITAL LETTER E WITH CIRCUM...
-      | 0xE1 0xBB 0x88          #L&       LATIN CAPITAL LETTER I WITH HOOK A...
-      | 0xE1 0xBB 0x8A          #L&       LATIN CAPITAL LETTER I WITH DOT BELOW
-      | 0xE1 0xBB 0x8C          #L&       LATIN CAPITAL LETTER O WITH DOT BELOW
-      | 0xE1 0xBB 0x8E          #L&       LATIN CAPITAL LETTER O WITH HOOK A...
-      | 0xE1 0xBB 0x90          #L&       LATIN CAPITAL LETTER O WITH CIRCUM...
-      | 0xE1 0xBB 0x92          #L&       LATIN CAPITAL LETTER O WITH CIRCUM...
-      | 0xE1 0xBB 0x94          #L&       LATIN CAPITAL LETTER O WITH CIRCUM...
-      | 0xE1 0xBB 0x96          #L&       LATIN CAPITAL LETTER O WITH CIRCUM...
-      | 0xE1 0xBB 0x98          #L&       LATIN CAPITAL LETTER O WITH CIRCUM...
-      | 0xE1 0xBB 0x9A          #L&       LATIN CAPITAL LETTER O WITH HORN A...
-      | 0xE1 0xBB 0x9C          #L&       LATIN CAPITAL LETTER O WITH HORN A...
-      | 0xE1 0xBB 0x9E          #L&       LATIN CAPITAL LETTER O WITH HORN A...
-      | 0xE1 0xBB 0xA0          #L&       LATIN CAPITAL LETTER O WITH HORN A...
-      | 0xE1 0xBB 0xA2          #L&       LATIN CAPITAL LETTER O WITH HORN A...
-      | 0xE1 0xBB 0xA4          #L&       LATIN CAPITAL LETTER U WITH DOT BELOW
-      | 0xE1 0xBB 0xA6          #L&       LATIN CAPITAL LETTER U WITH HOOK A...
-      | 0xE1 0xBB 0xA8          #L&       LATIN CAPITAL LETTER U WITH HORN A...
-      | 0xE1 0xBB 0xAA          #L&       LATIN CAPITAL LETTER U WITH HORN A...
-      | 0xE1 0xBB 0xAC          #L&       LATIN CAPITAL LETTER U WITH HORN A...
-      | 0xE1 0xBB 0xAE          #L&       LATIN CAPITAL LETTER U WITH HORN A...
-      | 0xE1 0xBB 0xB0          #L&       LATIN CAPITAL LETTER U WITH HORN A...
-      | 0xE1 0xBB 0xB2          #L&       LATIN CAPITAL LETTER Y WITH GRAVE
-      | 0xE1 0xBB 0xB4          #L&       LATIN CAPITAL LETTER Y WITH DOT BELOW
-      | 0xE1 0xBB 0xB6          #L&       LATIN CAPITAL LETTER Y WITH HOOK A...
-      | 0xE1 0xBB 0xB8          #L&       LATIN CAPITAL LETTER Y WITH TILDE
-      | 0xE1 0xBB 0xBA          #L&       LATIN CAPITAL LETTER MIDDLE-WELSH LL
-      | 0xE1 0xBB 0xBC          #L&       LATIN CAPITAL LETTER MIDDLE-WELSH V
-      | 0xE1 0xBB 0xBE          #L&       LATIN CAPITAL LETTER Y WITH LOOP
-      | 0xE1 0xBC 0x88..0x8F    #L&   [8] GREEK CAPITAL LETTER ALPHA WITH PS...
-      | 0xE1 0xBC 0x98..0x9D    #L&   [6] GREEK CAPITAL LETTER EPSILON WITH ...
-      | 0xE1 0xBC 0xA8..0xAF    #L&   [8] GREEK CAPITAL LETTER ETA WITH PSIL...
-      | 0xE1 0xBC 0xB8..0xBF    #L&   [8] GREEK CAPITAL LETTER IOTA WITH PSI...
-      | 0xE1 0xBD 0x88..0x8D    #L&   [6] GREEK CAPITAL LETTER OMICRON WITH ...
-      | 0xE1 0xBD 0x99          #L&       GREEK CAPITAL LETTER UPSILON WITH ...
-      | 0xE1 0xBD 0x9B          #L&       GREEK CAPITAL LETTER UPSILON WITH ...
-      | 0xE1 0xBD 0x9D          #L&       GREEK CAPITAL LETTER UPSILON WITH ...
-      | 0xE1 0xBD 0x9F          #L&       GREEK CAPITAL LETTER UPSILON WITH ...
-      | 0xE1 0xBD 0xA8..0xAF    #L&   [8] GREEK CAPITAL LETTER OMEGA WITH PS...
-      | 0xE1 0xBE 0xB8..0xBB    #L&   [4] GREEK CAPITAL LETTER ALPHA WITH VR...
-      | 0xE1 0xBF 0x88..0x8B    #L&   [4] GREEK CAPITAL LETTER EPSILON WITH ...
-      | 0xE1 0xBF 0x98..0x9B    #L&   [4] GREEK CAPITAL LETTER IOTA WITH VRA...
-      | 0xE1 0xBF 0xA8..0xAC    #L&   [5] GREEK CAPITAL LETTER UPSILON WITH ...
-      | 0xE1 0xBF 0xB8..0xBB    #L&   [4] GREEK CAPITAL LETTER OMICRON WITH ...
-      | 0xE2 0x84 0x82          #L&       DOUBLE-STRUCK CAPITAL C
-      | 0xE2 0x84 0x87          #L&       EULER CONSTANT
-      | 0xE2 0x84 0x8B..0x8D    #L&   [3] SCRIPT CAPITAL H..DOUBLE-STRUCK CA...
-      | 0xE2 0x84 0x90..0x92    #L&   [3] SCRIPT CAPITAL I..SCRIPT CAPITAL L
-      | 0xE2 0x84 0x95          #L&       DOUBLE-STRUCK CAPITAL N
-      | 0xE2 0x84 0x99..0x9D    #L&   [5] DOUBLE-STRUCK CAPITAL P..DOUBLE-ST...
-      | 0xE2 0x84 0xA4          #L&       DOUBLE-STRUCK CAPITAL Z
-      | 0xE2 0x84 0xA6          #L&       OHM SIGN
-      | 0xE2 0x84 0xA8          #L&       BLACK-LETTER CAPITAL Z
-      | 0xE2 0x84 0xAA..0xAD    #L&   [4] KELVIN SIGN..BLACK-LETTER CAPITAL C
-      | 0xE2 0x84 0xB0..0xB3    #L&   [4] SCRIPT CAPITAL E..SCRIPT CAPITAL M
-      | 0xE2 0x84 0xBE..0xBF    #L&   [2] DOUBLE-STRUCK CAPITAL GAMMA..DOUBL...
-      | 0xE2 0x85 0x85          #L&       DOUBLE-STRUCK ITALIC CAPITAL D
-      | 0xE2 0x85 0xA0..0xAF    #Nl  [16] ROMAN NUMERAL ONE..ROMAN NUMERAL O...
-      | 0xE2 0x86 0x83          #L&       ROMAN NUMERAL REVERSED ONE HUNDRED
-      | 0xE2 0x92 0xB6..0xFF    #So  [26] CIRCLED LATIN CAPITAL LETTER A..CI...
-      | 0xE2 0x93 0x00..0x8F    #
-      | 0xE2 0xB0 0x80..0xAE    #L&  [47] GLAGOLITIC CAPITAL LETTER AZU..GLA...
-      | 0xE2 0xB1 0xA0          #L&       LATIN CAPITAL LETTER L WITH DOUBLE...
-      | 0xE2 0xB1 0xA2..0xA4    #L&   [3] LATIN CAPITAL LETTER L WITH MIDDLE...
-      | 0xE2 0xB1 0xA7          #L&       LATIN CAPITAL LETTER H WITH DESCENDER
-      | 0xE2 0xB1 0xA9          #L&       LATIN CAPITAL LETTER K WITH DESCENDER
-      | 0xE2 0xB1 0xAB          #L&       LATIN CAPITAL LETTER Z WITH DESCENDER
-      | 0xE2 0xB1 0xAD..0xAF    #L&   [3] LATIN CAPITAL LETTER ALPHA..LATIN ...
-      | 0xE2 0xB1 0xB2          #L&       LATIN CAPITAL LETTER W WITH HOOK
-      | 0xE2 0xB1 0xB5          #L&       LATIN CAPITAL LETTER HALF H
-      | 0xE2 0xB2 0x80          #L&       COPTIC CAPITAL LETTER ALFA
-      | 0xE2 0xB2 0x82          #L&       COPTIC CAPITAL LETTER VIDA
-      | 0xE2 0xB2 0x84          #L&       COPTIC CAPITAL LETTER GAMMA
-      | 0xE2 0xB2 0x86          #L&       COPTIC CAPITAL LETTER DALDA
-      | 0xE2 0xB2 0x88          #L&       COPTIC CAPITAL LETTER EIE
-      | 0xE2 0xB2 0x8A          #L&       COPTIC CAPITAL LETTER SOU
-      | 0xE2 0xB2 0x8C          #L&       COPTIC CAPITAL LETTER ZATA
-      | 0xE2 0xB2 0x8E          #L&       COPTIC CAPITAL LETTER HATE
-      | 0xE2 0xB2 0x90          #L&       COPTIC CAPITAL LETTER THETHE
-      | 0xE2 0xB2 0x92          #L&       COPTIC CAPITAL LETTER IAUDA
-      | 0xE2 0xB2 0x94          #L&       COPTIC CAPITAL LETTER KAPA
-      | 0xE2 0xB2 0x96          #L&       COPTIC CAPITAL LETTER LAULA
-      | 0xE2 0xB2 0x98          #L&       COPTIC CAPITAL LETTER MI
-      | 0xE2 0xB2 0x9A          #L&       COPTIC CAPITAL LETTER NI
-      | 0xE2 0xB2 0x9C          #L&       COPTIC CAPITAL LETTER KSI
-      | 0xE2 0xB2 0x9E          #L&       COPTIC CAPITAL LETTER O
-      | 0xE2 0xB2 0xA0          #L&       COPTIC CAPITAL LETTER PI
-      | 0xE2 0xB2 0xA2          #L&       COPTIC CAPITAL LETTER RO
-      | 0xE2 0xB2 0xA4          #L&       COPTIC CAPITAL LETTER SIMA
-      | 0xE2 0xB2 0xA6          #L&       COPTIC CAPITAL LETTER TAU
-      | 0xE2 0xB2 0xA8          #L&       COPTIC CAPITAL LETTER UA
-      | 0xE2 0xB2 0xAA          #L&       COPTIC CAPITAL LETTER FI
-      | 0xE2 0xB2 0xAC          #L&       COPTIC CAPITAL LETTER KHI
-      | 0xE2 0xB2 0xAE          #L&       COPTIC CAPITAL LETTER PSI
-      | 0xE2 0xB2 0xB0          #L&       COPTIC CAPITAL LETTER OOU
-      | 0xE2 0xB2 0xB2          #L&       COPTIC CAPITAL LETTER DIALECT-P ALEF
-      | 0xE2 0xB2 0xB4          #L&       COPTIC CAPITAL LETTER OLD COPTIC AIN
-      | 0xE2 0xB2 0xB6          #L&       COPTIC CAPITAL LETTER CRYPTOGRAMMI...
-      | 0xE2 0xB2 0xB8          #L&       COPTIC CAPITAL LETTER DIALECT-P KAPA
-      | 0xE2 0xB2 0xBA          #L&       COPTIC CAPITAL LETTER DIALECT-P NI
-      | 0xE2 0xB2 0xBC          #L&       COPTIC CAPITAL LETTER CRYPTOGRAMMI...
-      | 0xE2 0xB2 0xBE          #L&       COPTIC CAPITAL LETTER OLD COPTIC OOU
-      | 0xE2 0xB3 0x80          #L&       COPTIC CAPITAL LETTER SAMPI
-      | 0xE2 0xB3 0x82          #L&       COPTIC CAPITAL LETTER CROSSED SHEI
-      | 0xE2 0xB3 0x84          #L&       COPTIC CAPITAL LETTER OLD COPTIC SHEI
-      | 0xE2 0xB3 0x86          #L&       COPTIC CAPITAL LETTER OLD COPTIC ESH
-      | 0xE2 0xB3 0x88          #L&       COPTIC CAPITAL LETTER AKHMIMIC KHEI
-      | 0xE2 0xB3 0x8A          #L&       COPTIC CAPITAL LETTER DIALECT-P HORI
-      | 0xE2 0xB3 0x8C          #L&       COPTIC CAPITAL LETTER OLD COPTIC HORI
-      | 0xE2 0xB3 0x8E          #L&       COPTIC CAPITAL LETTER OLD COPTIC HA
-      | 0xE2 0xB3 0x90          #L&       COPTIC CAPITAL LETTER L-SHAPED HA
-      | 0xE2 0xB3 0x92          #L&       COPTIC CAPITAL LETTER OLD COPTIC HEI
-      | 0xE2 0xB3 0x94          #L&       COPTIC CAPITAL LETTER OLD COPTIC HAT
-      | 0xE2 0xB3 0x96          #L&       COPTIC CAPITAL LETTER OLD COPTIC G...
-      | 0xE2 0xB3 0x98          #L&       COPTIC CAPITAL LETTER OLD COPTIC DJA
-      | 0xE2 0xB3 0x9A          #L&       COPTIC CAPITAL LETTER OLD COPTIC S...
-      | 0xE2 0xB3 0x9C          #L&       COPTIC CAPITAL LETTER OLD NUBIAN S...
-      | 0xE2 0xB3 0x9E          #L&       COPTIC CAPITAL LETTER OLD NUBIAN NGI
-      | 0xE2 0xB3 0xA0          #L&       COPTIC CAPITAL LETTER OLD NUBIAN NYI
-      | 0xE2 0xB3 0xA2          #L&       COPTIC CAPITAL LETTER OLD NUBIAN WAU
-      | 0xEA 0x99 0x80          #L&       CYRILLIC CAPITAL LETTER ZEMLYA
-      | 0xEA 0x99 0x82          #L&       CYRILLIC CAPITAL LETTER DZELO
-      | 0xEA 0x99 0x84          #L&       CYRILLIC CAPITAL LETTER REVERSED DZE
-      | 0xEA 0x99 0x86          #L&       CYRILLIC CAPITAL LETTER IOTA
-      | 0xEA 0x99 0x88          #L&       CYRILLIC CAPITAL LETTER DJERV
-      | 0xEA 0x99 0x8A          #L&       CYRILLIC CAPITAL LETTER MONOGRAPH UK
-      | 0xEA 0x99 0x8C          #L&       CYRILLIC CAPITAL LETTER BROAD OMEGA
-      | 0xEA 0x99 0x8E          #L&       CYRILLIC CAPITAL LETTER NEUTRAL YER
-      | 0xEA 0x99 0x90          #L&       CYRILLIC CAPITAL LETTER YERU WITH ...
-      | 0xEA 0x99 0x92          #L&       CYRILLIC CAPITAL LETTER IOTIFIED YAT
-      | 0xEA 0x99 0x94          #L&       CYRILLIC CAPITAL LETTER REVERSED YU
-      | 0xEA 0x99 0x96          #L&       CYRILLIC CAPITAL LETTER IOTIFIED A
-      | 0xEA 0x99 0x98          #L&       CYRILLIC CAPITAL LETTER CLOSED LIT...
-      | 0xEA 0x99 0x9A          #L&       CYRILLIC CAPITAL LETTER BLENDED YUS
-      | 0xEA 0x99 0x9C          #L&       CYRILLIC CAPITAL LETTER IOTIFIED C...
-      | 0xEA 0x99 0x9E          #L&       CYRILLIC CAPITAL LETTER YN
-      | 0xEA 0x99 0xA2          #L&       CYRILLIC CAPITAL LETTER SOFT DE
-      | 0xEA 0x99 0xA4          #L&       CYRILLIC CAPITAL LETTER SOFT EL
-      | 0xEA 0x99 0xA6          #L&       CYRILLIC CAPITAL LETTER SOFT EM
-      | 0xEA 0x99 0xA8          #L&       CYRILLIC CAPITAL LETTER MONOCULAR O
-      | 0xEA 0x99 0xAA          #L&       CYRILLIC CAPITAL LETTER BINOCULAR O
-      | 0xEA 0x99 0xAC          #L&       CYRILLIC CAPITAL LETTER DOUBLE MON...
-      | 0xEA 0x9A 0x80          #L&       CYRILLIC CAPITAL LETTER DWE
-      | 0xEA 0x9A 0x82          #L&       CYRILLIC CAPITAL LETTER DZWE
-      | 0xEA 0x9A 0x84          #L&       CYRILLIC CAPITAL LETTER ZHWE
-      | 0xEA 0x9A 0x86          #L&       CYRILLIC CAPITAL LETTER CCHE
-      | 0xEA 0x9A 0x88          #L&       CYRILLIC CAPITAL LETTER DZZE
-      | 0xEA 0x9A 0x8A          #L&       CYRILLIC CAPITAL LETTER TE WITH MI...
-      | 0xEA 0x9A 0x8C          #L&       CYRILLIC CAPITAL LETTER TWE
-      | 0xEA 0x9A 0x8E          #L&       CYRILLIC CAPITAL LETTER TSWE
-      | 0xEA 0x9A 0x90          #L&       CYRILLIC CAPITAL LETTER TSSE
-      | 0xEA 0x9A 0x92          #L&       CYRILLIC CAPITAL LETTER TCHE
-      | 0xEA 0x9A 0x94          #L&       CYRILLIC CAPITAL LETTER HWE
-      | 0xEA 0x9A 0x96          #L&       CYRILLIC CAPITAL LETTER SHWE
-      | 0xEA 0x9C 0xA2          #L&       LATIN CAPITAL LETTER EGYPTOLOGICAL...
-      | 0xEA 0x9C 0xA4          #L&       LATIN CAPITAL LETTER EGYPTOLOGICAL...
-      | 0xEA 0x9C 0xA6          #L&       LATIN CAPITAL LETTER HENG
-      | 0xEA 0x9C 0xA8          #L&       LATIN CAPITAL LETTER TZ
-      | 0xEA 0x9C 0xAA          #L&       LATIN CAPITAL LETTER TRESILLO
-      | 0xEA 0x9C 0xAC          #L&       LATIN CAPITAL LETTER CUATRILLO
-      | 0xEA 0x9C 0xAE          #L&       LATIN CAPITAL LETTER CUATRILLO WIT...
-      | 0xEA 0x9C 0xB2          #L&       LATIN CAPITAL LETTER AA
-      | 0xEA 0x9C 0xB4          #L&       LATIN CAPITAL LETTER AO
-      | 0xEA 0x9C 0xB6          #L&       LATIN CAPITAL LETTER AU
-      | 0xEA 0x9C 0xB8          #L&       LATIN CAPITAL LETTER AV
-      | 0xEA 0x9C 0xBA          #L&       LATIN CAPITAL LETTER AV WITH HORIZ...
-      | 0xEA 0x9C 0xBC          #L&       LATIN CAPITAL LETTER AY
-      | 0xEA 0x9C 0xBE          #L&       LATIN CAPITAL LETTER REVERSED C WI...
-      | 0xEA 0x9D 0x80          #L&       LATIN CAPITAL LETTER K WITH STROKE
-      | 0xEA 0x9D 0x82          #L&       LATIN CAPITAL LETTER K WITH DIAGON...
-      | 0xEA 0x9D 0x84          #L&       LATIN CAPITAL LETTER K WITH STROKE...
-      | 0xEA 0x9D 0x86          #L&       LATIN CAPITAL LETTER BROKEN L
-      | 0xEA 0x9D 0x88          #L&       LATIN CAPITAL LETTER L WITH HIGH S...
-      | 0xEA 0x9D 0x8A          #L&       LATIN CAPITAL LETTER O WITH LONG S...
-      | 0xEA 0x9D 0x8C          #L&       LATIN CAPITAL LETTER O WITH LOOP
-      | 0xEA 0x9D 0x8E          #L&       LATIN CAPITAL LETTER OO
-      | 0xEA 0x9D 0x90          #L&       LATIN CAPITAL LETTER P WITH STROKE...
-      | 0xEA 0x9D 0x92          #L&       LATIN CAPITAL LETTER P WITH FLOURISH
-      | 0xEA 0x9D 0x94          #L&       LATIN CAPITAL LETTER P WITH SQUIRR...
-      | 0xEA 0x9D 0x96          #L&       LATIN CAPITAL LETTER Q WITH STROKE...
-      | 0xEA 0x9D 0x98          #L&       LATIN CAPITAL LETTER Q WITH DIAGON...
-      | 0xEA 0x9D 0x9A          #L&       LATIN CAPITAL LETTER R ROTUNDA
-      | 0xEA 0x9D 0x9C          #L&       LATIN CAPITAL LETTER RUM ROTUNDA
-      | 0xEA 0x9D 0x9E          #L&       LATIN CAPITAL LETTER V WITH DIAGON...
-      | 0xEA 0x9D 0xA0          #L&       LATIN CAPITAL LETTER VY
-      | 0xEA 0x9D 0xA2          #L&       LATIN CAPITAL LETTER VISIGOTHIC Z
-      | 0xEA 0x9D 0xA4          #L&       LATIN CAPITAL LETTER THORN WITH ST...
-      | 0xEA 0x9D 0xA6          #L&       LATIN CAPITAL LETTER THORN WITH ST...
-      | 0xEA 0x9D 0xA8          #L&       LATIN CAPITAL LETTER VEND
-      | 0xEA 0x9D 0xAA          #L&       LATIN CAPITAL LETTER ET
-      | 0xEA 0x9D 0xAC          #L&       LATIN CAPITAL LETTER IS
-      | 0xEA 0x9D 0xAE          #L&       LATIN CAPITAL LETTER CON
-      | 0xEA 0x9D 0xB9          #L&       LATIN CAPITAL LETTER INSULAR D
-      | 0xEA 0x9D 0xBB          #L&       LATIN CAPITAL LETTER INSULAR F
-      | 0xEA 0x9D 0xBD..0xBE    #L&   [2] LATIN CAPITAL LETTER INSULAR G..LA...
-      | 0xEA 0x9E 0x80          #L&       LATIN CAPITAL LETTER TURNED L
-      | 0xEA 0x9E 0x82          #L&       LATIN CAPITAL LETTER INSULAR R
-      | 0xEA 0x9E 0x84          #L&       LATIN CAPITAL LETTER INSULAR S
-      | 0xEA 0x9E 0x86          #L&       LATIN CAPITAL LETTER INSULAR T
-      | 0xEA 0x9E 0x8B          #L&       LATIN CAPITAL LETTER SALTILLO
-      | 0xEF 0xBC 0xA1..0xBA    #L&  [26] FULLWIDTH LATIN CAPITAL LETTER A.....
-      | 0xF0 0x90 0x90 0x80..0xA7  #L&  [40] DESERET CAPITAL LETTER LONG I.....
-      | 0xF0 0x9D 0x90 0x80..0x99  #L&  [26] MATHEMATICAL BOLD CAPITAL A..MA...
-      | 0xF0 0x9D 0x90 0xB4..0xFF  #L&  [26] MATHEMATICAL ITALIC CAPITAL A.....
-      | 0xF0 0x9D 0x91 0x00..0x8D  #
-      | 0xF0 0x9D 0x91 0xA8..0xFF  #L&  [26] MATHEMATICAL BOLD ITALIC CAPITA...
-      | 0xF0 0x9D 0x92 0x00..0x81  #
-      | 0xF0 0x9D 0x92 0x9C     #L&       MATHEMATICAL SCRIPT CAPITAL A
-      | 0xF0 0x9D 0x92 0x9E..0x9F  #L&   [2] MATHEMATICAL SCRIPT CAPITAL C.....
-      | 0xF0 0x9D 0x92 0xA2     #L&       MATHEMATICAL SCRIPT CAPITAL G
-      | 0xF0 0x9D 0x92 0xA5..0xA6  #L&   [2] MATHEMATICAL SCRIPT CAPITAL J.....
-      | 0xF0 0x9D 0x92 0xA9..0xAC  #L&   [4] MATHEMATICAL SCRIPT CAPITAL N.....
-      | 0xF0 0x9D 0x92 0xAE..0xB5  #L&   [8] MATHEMATICAL SCRIPT CAPITAL S.....
-      | 0xF0 0x9D 0x93 0x90..0xA9  #L&  [26] MATHEMATICAL BOLD SCRIPT CAPITA...
-      | 0xF0 0x9D 0x94 0x84..0x85  #L&   [2] MATHEMATICAL FRAKTUR CAPITAL A....
-      | 0xF0 0x9D 0x94 0x87..0x8A  #L&   [4] MATHEMATICAL FRAKTUR CAPITAL D....
-      | 0xF0 0x9D 0x94 0x8D..0x94  #L&   [8] MATHEMATICAL FRAKTUR CAPITAL J....
-      | 0xF0 0x9D 0x94 0x96..0x9C  #L&   [7] MATHEMATICAL FRAKTUR CAPITAL S....
-      | 0xF0 0x9D 0x94 0xB8..0xB9  #L&   [2] MATHEMATICAL DOUBLE-STRUCK CAPI...
-      | 0xF0 0x9D 0x94 0xBB..0xBE  #L&   [4] MATHEMATICAL DOUBLE-STRUCK CAPI...
-      | 0xF0 0x9D 0x95 0x80..0x84  #L&   [5] MATHEMATICAL DOUBLE-STRUCK CAPI...
-      | 0xF0 0x9D 0x95 0x86     #L&       MATHEMATICAL DOUBLE-STRUCK CAPITAL O
-      | 0xF0 0x9D 0x95 0x8A..0x90  #L&   [7] MATHEMATICAL DOUBLE-STRUCK CAPI...
-      | 0xF0 0x9D 0x95 0xAC..0xFF  #L&  [26] MATHEMATICAL BOLD FRAKTUR CAPIT...
-      | 0xF0 0x9D 0x96 0x00..0x85  #
-      | 0xF0 0x9D 0x96 0xA0..0xB9  #L&  [26] MATHEMATICAL SANS-SERIF CAPITAL...
-      | 0xF0 0x9D 0x97 0x94..0xAD  #L&  [26] MATHEMATICAL SANS-SERIF BOLD CA...
-      | 0xF0 0x9D 0x98 0x88..0xA1  #L&  [26] MATHEMATICAL SANS-SERIF ITALIC ...
-      | 0xF0 0x9D 0x98 0xBC..0xFF  #L&  [26] MATHEMATICAL SANS-SERIF BOLD IT...
-      | 0xF0 0x9D 0x99 0x00..0x95  #
-      | 0xF0 0x9D 0x99 0xB0..0xFF  #L&  [26] MATHEMATICAL MONOSPACE CAPITAL ...
-      | 0xF0 0x9D 0x9A 0x00..0x89  #
-      | 0xF0 0x9D 0x9A 0xA8..0xFF  #L&  [25] MATHEMATICAL BOLD CAPITAL ALPHA...
-      | 0xF0 0x9D 0x9B 0x00..0x80  #
-      | 0xF0 0x9D 0x9B 0xA2..0xBA  #L&  [25] MATHEMATICAL ITALIC CAPITAL ALP...
-      | 0xF0 0x9D 0x9C 0x9C..0xB4  #L&  [25] MATHEMATICAL BOLD ITALIC CAPITA...
-      | 0xF0 0x9D 0x9D 0x96..0xAE  #L&  [25] MATHEMATICAL SANS-SERIF BOLD CA...
-      | 0xF0 0x9D 0x9E 0x90..0xA8  #L&  [25] MATHEMATICAL SANS-SERIF BOLD IT...
-      | 0xF0 0x9D 0x9F 0x8A     #L&       MATHEMATICAL BOLD CAPITAL DIGAMMA
-      ;
-
-    udigit = '0'..'9';
-    ualnum = ualpha | udigit;
-}%%